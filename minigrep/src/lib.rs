@@ -0,0 +1,179 @@
+//! The I/O project of chapter 12 of The Book: a small 'grep' that prints the
+//! lines of a file containing a query.
+//!
+//! ```text
+//! cargo run -p minigrep -- to minigrep/poem.txt
+//! IGNORE_CASE=1 cargo run -p minigrep -- to minigrep/poem.txt
+//! ```
+//!
+//! See <https://doc.rust-lang.org/book/ch12-00-an-io-project.html>.
+
+use std::env;
+use std::error::Error;
+use std::fs;
+
+
+/// What to search for, and where.
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub query: String,
+    pub file_path: String,
+    pub ignore_case: bool,
+}
+
+
+impl Config {
+    /// Builds a Config from the command line, whose first element is the
+    /// program name. The search ignores case when the 'IGNORE_CASE'
+    /// environment variable is set, to any value.
+    ///
+    /// ```
+    /// use minigrep::Config;
+    ///
+    /// let args: Vec<String> = vec!["minigrep".to_string(), "to".to_string()];
+    /// assert_eq!(Config::build(&args), Err("not enough arguments"));
+    /// ```
+    pub fn build(args: &[String]) -> Result<Config, &'static str> {
+        if args.len() < 3 {
+            return Err("not enough arguments");
+        }
+
+        let query: String = args[1].clone();
+        let file_path: String = args[2].clone();
+        let ignore_case: bool = env::var("IGNORE_CASE").is_ok();
+
+        Ok(Config { query, file_path, ignore_case })
+    }
+}
+
+
+/// Prints the matching lines of the file; the caller reports any error.
+pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
+    let contents: String = fs::read_to_string(config.file_path)?;
+
+    let results: Vec<&str> = if config.ignore_case {
+        search_case_insensitive(&config.query, &contents)
+    } else {
+        search(&config.query, &contents)
+    };
+
+    for line in results {
+        println!("{line}");
+    }
+
+    Ok(())
+}
+
+
+/// The lines of `contents` containing `query`.
+///
+/// ```
+/// let contents: &str = "Rust:\nsafe, fast, productive.\nPick three.";
+/// assert_eq!(minigrep::search("duct", contents), vec!["safe, fast, productive."]);
+/// ```
+pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    let mut results: Vec<&str> = Vec::new();
+
+    for line in contents.lines() {
+        if line.contains(query) {
+            results.push(line);
+        }
+    }
+
+    results
+}
+
+
+/// Like `search`, ignoring case.
+pub fn search_case_insensitive<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    let query: String = query.to_lowercase();
+    let mut results: Vec<&str> = Vec::new();
+
+    for line in contents.lines() {
+        if line.to_lowercase().contains(&query) {
+            results.push(line);
+        }
+    }
+
+    results
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn build_config() {
+        let config: Config = Config::build(&args(&["minigrep", "to", "poem.txt"])).unwrap();
+        assert_eq!(config.query, "to");
+        assert_eq!(config.file_path, "poem.txt");
+    }
+
+    #[test]
+    fn build_ignores_extra_arguments() {
+        let config: Config = Config::build(&args(&["minigrep", "to", "poem.txt", "-v"])).unwrap();
+        assert_eq!(config.file_path, "poem.txt");
+    }
+
+    #[test]
+    fn not_enough_arguments() {
+        assert_eq!(Config::build(&args(&["minigrep"])), Err("not enough arguments"));
+        assert_eq!(Config::build(&args(&["minigrep", "to"])), Err("not enough arguments"));
+    }
+
+    #[test]
+    fn case_sensitive() {
+        let query: &str = "duct";
+        let contents: &str = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Duct tape.";
+
+        assert_eq!(vec!["safe, fast, productive."], search(query, contents));
+    }
+
+    #[test]
+    fn case_insensitive() {
+        let query: &str = "rUsT";
+        let contents: &str = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Trust me.";
+
+        assert_eq!(
+            vec!["Rust:", "Trust me."],
+            search_case_insensitive(query, contents)
+        );
+    }
+
+    #[test]
+    fn no_match() {
+        assert!(search("monomorphization", "Rust:\nsafe, fast, productive.").is_empty());
+        assert!(search("rust", "").is_empty());
+    }
+
+    #[test]
+    fn empty_query_matches_every_line() {
+        assert_eq!(search("", "a\nb"), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn missing_file() {
+        let config = Config {
+            query: String::from("to"),
+            file_path: String::from("surely/missing/poem.txt"),
+            ignore_case: false,
+        };
+        let error: Box<dyn Error> = run(config).unwrap_err();
+        let io_error: &io::Error = error.downcast_ref::<io::Error>().unwrap();
+        assert_eq!(io_error.kind(), io::ErrorKind::NotFound);
+    }
+}
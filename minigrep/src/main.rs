@@ -0,0 +1,21 @@
+use std::env;
+use std::process;
+
+use minigrep::Config;
+
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    // errors go to stderr, so that 'minigrep to poem.txt > output.txt' only
+    // redirects the results.
+    let config: Config = Config::build(&args).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        process::exit(1);
+    });
+
+    if let Err(e) = minigrep::run(config) {
+        eprintln!("Application error: {e}");
+        process::exit(1);
+    }
+}
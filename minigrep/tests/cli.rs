@@ -0,0 +1,65 @@
+//! Runs the minigrep binary and checks what it writes to stdout and stderr.
+
+use std::process::{Command, Output};
+
+
+fn minigrep(args: &[&str], ignore_case: bool) -> Output {
+    let mut command: Command = Command::new(env!("CARGO_BIN_EXE_minigrep"));
+    command.args(args).current_dir(env!("CARGO_MANIFEST_DIR"));
+    if ignore_case {
+        command.env("IGNORE_CASE", "1");
+    } else {
+        command.env_remove("IGNORE_CASE");
+    }
+    command.output().expect("failed to run minigrep")
+}
+
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+
+#[test]
+fn prints_matching_lines() {
+    let output: Output = minigrep(&["frog", "poem.txt"], false);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "How public, like a frog\n");
+    assert_eq!(stderr(&output), "");
+}
+
+
+#[test]
+fn ignore_case_from_environment() {
+    let output: Output = minigrep(&["to", "poem.txt"], false);
+    assert_eq!(stdout(&output), "Are you nobody, too?\nHow dreary to be somebody!\n");
+
+    let output: Output = minigrep(&["to", "poem.txt"], true);
+    assert_eq!(
+        stdout(&output),
+        "Are you nobody, too?\nHow dreary to be somebody!\nTo tell your name the livelong day\nTo an admiring bog!\n"
+    );
+}
+
+
+#[test]
+fn usage_error_goes_to_stderr() {
+    let output: Output = minigrep(&["frog"], false);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "");
+    assert_eq!(stderr(&output), "Problem parsing arguments: not enough arguments\n");
+}
+
+
+#[test]
+fn missing_file_goes_to_stderr() {
+    let output: Output = minigrep(&["frog", "missing.txt"], false);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "");
+    assert!(stderr(&output).starts_with("Application error: "), "stderr was `{}`", stderr(&output));
+}
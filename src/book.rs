@@ -0,0 +1,391 @@
+//! The table of contents of The Book, to find out which of its sections the
+//! examples cover.
+//!
+//! A section is covered by the examples whose `book` page is the section's
+//! page. It is only partially covered when all of these examples are
+//! placeholders with an empty body, and missing when there are none.
+
+use crate::registry::{self, Example};
+use crate::source;
+
+
+#[derive(Clone, Copy, Debug)]
+pub struct BookChapter {
+    pub number: u32,
+    pub title: &'static str,
+    pub sections: &'static [Section],
+}
+
+
+#[derive(Clone, Copy, Debug)]
+pub struct Section {
+    /// Page of the section, relative to `registry::BOOK_URL`.
+    pub page: &'static str,
+    pub title: &'static str,
+}
+
+
+macro_rules! sections {
+    ($($page:literal => $title:literal,)*) => {
+        &[$(Section { page: concat!($page, ".html"), title: $title },)*]
+    };
+}
+
+
+pub const OUTLINE: &[BookChapter] = &[
+    BookChapter {
+        number: 1, title: "Getting Started",
+        sections: sections! {
+            "ch01-01-installation" => "Installation",
+            "ch01-02-hello-world" => "Hello, World!",
+            "ch01-03-hello-cargo" => "Hello, Cargo!",
+        },
+    },
+    BookChapter {
+        number: 2, title: "Programming a Guessing Game",
+        sections: sections! {
+            "ch02-00-guessing-game-tutorial" => "Programming a Guessing Game",
+        },
+    },
+    BookChapter {
+        number: 3, title: "Common Programming Concepts",
+        sections: sections! {
+            "ch03-01-variables-and-mutability" => "Variables and Mutability",
+            "ch03-02-data-types" => "Data Types",
+            "ch03-03-how-functions-work" => "Functions",
+            "ch03-04-comments" => "Comments",
+            "ch03-05-control-flow" => "Control Flow",
+        },
+    },
+    BookChapter {
+        number: 4, title: "Understanding Ownership",
+        sections: sections! {
+            "ch04-01-what-is-ownership" => "What is Ownership?",
+            "ch04-02-references-and-borrowing" => "References and Borrowing",
+            "ch04-03-slices" => "The Slice Type",
+        },
+    },
+    BookChapter {
+        number: 5, title: "Using Structs to Structure Related Data",
+        sections: sections! {
+            "ch05-01-defining-structs" => "Defining and Instantiating Structs",
+            "ch05-02-example-structs" => "An Example Program Using Structs",
+            "ch05-03-method-syntax" => "Method Syntax",
+        },
+    },
+    BookChapter {
+        number: 6, title: "Enums and Pattern Matching",
+        sections: sections! {
+            "ch06-01-defining-an-enum" => "Defining an Enum",
+            "ch06-02-match" => "The match Control Flow Construct",
+            "ch06-03-if-let" => "Concise Control Flow with if let",
+        },
+    },
+    BookChapter {
+        number: 7, title: "Managing Growing Projects with Packages, Crates, and Modules",
+        sections: sections! {
+            "ch07-01-packages-and-crates" => "Packages and Crates",
+            "ch07-02-defining-modules-to-control-scope-and-privacy" =>
+                "Defining Modules to Control Scope and Privacy",
+            "ch07-03-paths-for-referring-to-an-item-in-the-module-tree" =>
+                "Paths for Referring to an Item in the Module Tree",
+            "ch07-04-bringing-paths-into-scope-with-the-use-keyword" =>
+                "Bringing Paths Into Scope with the use Keyword",
+            "ch07-05-separating-modules-into-different-files" =>
+                "Separating Modules into Different Files",
+        },
+    },
+    BookChapter {
+        number: 8, title: "Common Collections",
+        sections: sections! {
+            "ch08-01-vectors" => "Storing Lists of Values with Vectors",
+            "ch08-02-strings" => "Storing UTF-8 Encoded Text with Strings",
+            "ch08-03-hash-maps" => "Storing Keys with Associated Values in Hash Maps",
+        },
+    },
+    BookChapter {
+        number: 9, title: "Error Handling",
+        sections: sections! {
+            "ch09-01-unrecoverable-errors-with-panic" => "Unrecoverable Errors with panic!",
+            "ch09-02-recoverable-errors-with-result" => "Recoverable Errors with Result",
+            "ch09-03-to-panic-or-not-to-panic" => "To panic! or Not to panic!",
+        },
+    },
+    BookChapter {
+        number: 10, title: "Generic Types, Traits, and Lifetimes",
+        sections: sections! {
+            "ch10-01-syntax" => "Generic Data Types",
+            "ch10-02-traits" => "Traits: Defining Shared Behavior",
+            "ch10-03-lifetime-syntax" => "Validating References with Lifetimes",
+        },
+    },
+    BookChapter {
+        number: 11, title: "Writing Automated Tests",
+        sections: sections! {
+            "ch11-01-writing-tests" => "How to Write Tests",
+            "ch11-02-running-tests" => "Controlling How Tests Are Run",
+            "ch11-03-test-organization" => "Test Organization",
+        },
+    },
+    BookChapter {
+        number: 12, title: "An I/O Project: Building a Command Line Program",
+        sections: sections! {
+            "ch12-01-accepting-command-line-arguments" => "Accepting Command Line Arguments",
+            "ch12-02-reading-a-file" => "Reading a File",
+            "ch12-03-improving-error-handling-and-modularity" =>
+                "Refactoring to Improve Modularity and Error Handling",
+            "ch12-04-testing-the-librarys-functionality" =>
+                "Developing the Library's Functionality with Test Driven Development",
+            "ch12-05-working-with-environment-variables" => "Working with Environment Variables",
+            "ch12-06-writing-to-stderr-instead-of-stdout" =>
+                "Writing Error Messages to Standard Error Instead of Standard Output",
+        },
+    },
+    BookChapter {
+        number: 13, title: "Functional Language Features: Iterators and Closures",
+        sections: sections! {
+            "ch13-01-closures" => "Closures",
+            "ch13-02-iterators" => "Processing a Series of Items with Iterators",
+            "ch13-03-improving-our-io-project" => "Improving Our I/O Project",
+            "ch13-04-performance" => "Comparing Performance: Loops vs. Iterators",
+        },
+    },
+    BookChapter {
+        number: 14, title: "More About Cargo and Crates.io",
+        sections: sections! {
+            "ch14-01-release-profiles" => "Customizing Builds with Release Profiles",
+            "ch14-02-publishing-to-crates-io" => "Publishing a Crate to Crates.io",
+            "ch14-03-cargo-workspaces" => "Cargo Workspaces",
+            "ch14-04-installing-binaries" => "Installing Binaries with cargo install",
+            "ch14-05-extending-cargo" => "Extending Cargo with Custom Commands",
+        },
+    },
+    BookChapter {
+        number: 15, title: "Smart Pointers",
+        sections: sections! {
+            "ch15-01-box" => "Using Box<T> to Point to Data on the Heap",
+            "ch15-02-deref" => "Treating Smart Pointers Like Regular References",
+            "ch15-03-drop" => "Running Code on Cleanup with the Drop Trait",
+            "ch15-04-rc" => "Rc<T>, the Reference Counted Smart Pointer",
+            "ch15-05-interior-mutability" => "RefCell<T> and the Interior Mutability Pattern",
+            "ch15-06-reference-cycles" => "Reference Cycles Can Leak Memory",
+        },
+    },
+    BookChapter {
+        number: 16, title: "Fearless Concurrency",
+        sections: sections! {
+            "ch16-01-threads" => "Using Threads to Run Code Simultaneously",
+            "ch16-02-message-passing" => "Transfer Data Between Threads with Message Passing",
+            "ch16-03-shared-state" => "Shared-State Concurrency",
+            "ch16-04-extensible-concurrency-sync-and-send" =>
+                "Extensible Concurrency with Send and Sync",
+        },
+    },
+    BookChapter {
+        number: 17, title: "Fundamentals of Asynchronous Programming",
+        sections: sections! {
+            "ch17-01-futures-and-syntax" => "Futures and the Async Syntax",
+            "ch17-02-concurrency-with-async" => "Applying Concurrency with Async",
+            "ch17-03-more-futures" => "Working With Any Number of Futures",
+            "ch17-04-streams" => "Streams: Futures in Sequence",
+            "ch17-05-traits-for-async" => "A Closer Look at the Traits for Async",
+            "ch17-06-futures-tasks-threads" => "Futures, Tasks, and Threads",
+        },
+    },
+    BookChapter {
+        number: 18, title: "Object Oriented Programming Features",
+        sections: sections! {
+            "ch18-01-what-is-oo" => "Characteristics of Object-Oriented Languages",
+            "ch18-02-trait-objects" => "Using Trait Objects to Abstract over Shared Behavior",
+            "ch18-03-oo-design-patterns" => "Implementing an Object-Oriented Design Pattern",
+        },
+    },
+    BookChapter {
+        number: 19, title: "Patterns and Matching",
+        sections: sections! {
+            "ch19-01-all-the-places-for-patterns" => "All the Places Patterns Can Be Used",
+            "ch19-02-refutability" => "Refutability: Whether a Pattern Might Fail to Match",
+            "ch19-03-pattern-syntax" => "Pattern Syntax",
+        },
+    },
+    BookChapter {
+        number: 20, title: "Advanced Features",
+        sections: sections! {
+            "ch20-01-unsafe-rust" => "Unsafe Rust",
+            "ch20-02-advanced-traits" => "Advanced Traits",
+            "ch20-03-advanced-types" => "Advanced Types",
+            "ch20-04-advanced-functions-and-closures" => "Advanced Functions and Closures",
+            "ch20-05-macros" => "Macros",
+        },
+    },
+    BookChapter {
+        number: 21, title: "Final Project: Building a Multithreaded Web Server",
+        sections: sections! {
+            "ch21-01-single-threaded" => "Building a Single-Threaded Web Server",
+            "ch21-02-multithreaded" => "From Single-Threaded to Multithreaded Server",
+            "ch21-03-graceful-shutdown-and-cleanup" => "Graceful Shutdown and Cleanup",
+        },
+    },
+];
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Status {
+    Covered,
+    /// Only placeholder examples point at the section.
+    Partial,
+    Missing,
+}
+
+
+impl Status {
+    pub fn name(self) -> &'static str {
+        match self {
+            Status::Covered => "covered",
+            Status::Partial => "partial",
+            Status::Missing => "missing",
+        }
+    }
+}
+
+
+#[derive(Clone, Debug)]
+pub struct SectionCoverage {
+    pub section: &'static Section,
+    pub status: Status,
+    pub examples: Vec<&'static Example>,
+}
+
+
+#[derive(Clone, Debug)]
+pub struct ChapterCoverage {
+    pub chapter: &'static BookChapter,
+    pub sections: Vec<SectionCoverage>,
+}
+
+
+impl ChapterCoverage {
+    /// Share of the sections that are covered, from 0 to 100, where a partly
+    /// covered section counts for half.
+    pub fn percent(&self) -> f64 {
+        percent(&self.sections)
+    }
+}
+
+
+fn percent(sections: &[SectionCoverage]) -> f64 {
+    if sections.is_empty() {
+        return 0.0;
+    }
+    let points: f64 = sections.iter()
+        .map(|s| match s.status {
+            Status::Covered => 1.0,
+            Status::Partial => 0.5,
+            Status::Missing => 0.0,
+        })
+        .sum();
+    points * 100.0 / sections.len() as f64
+}
+
+
+/// The page an example links to, without the anchor.
+pub fn page_of(example: &Example) -> &'static str {
+    example.book.split('#').next().unwrap_or_default()
+}
+
+
+pub fn find_section(page: &str) -> Option<&'static Section> {
+    OUTLINE.iter().flat_map(|chapter| chapter.sections).find(|s| s.page == page)
+}
+
+
+/// Whether `example` is a placeholder, ie. its function body is empty.
+pub fn is_placeholder(example: &Example) -> bool {
+    source::snippet_of(example).is_some_and(|snippet| snippet.body_is_empty())
+}
+
+
+pub fn coverage() -> Vec<ChapterCoverage> {
+    OUTLINE.iter()
+        .map(|chapter| ChapterCoverage {
+            chapter,
+            sections: chapter.sections.iter()
+                .map(|section| {
+                    let examples: Vec<&'static Example> = registry::all()
+                        .filter(|example| page_of(example) == section.page)
+                        .collect();
+                    let status: Status = if examples.is_empty() {
+                        Status::Missing
+                    } else if examples.iter().all(|example| is_placeholder(example)) {
+                        Status::Partial
+                    } else {
+                        Status::Covered
+                    };
+                    SectionCoverage { section, status, examples }
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+
+/// Coverage of the whole book, from 0 to 100.
+pub fn total_percent(chapters: &[ChapterCoverage]) -> f64 {
+    let sections: Vec<SectionCoverage> = chapters.iter()
+        .flat_map(|chapter| chapter.sections.iter().cloned())
+        .collect();
+    percent(&sections)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status_of(chapters: &[ChapterCoverage], page: &str) -> Status {
+        chapters.iter()
+            .flat_map(|chapter| &chapter.sections)
+            .find(|s| s.section.page == page)
+            .unwrap()
+            .status
+    }
+
+    #[test]
+    fn every_example_links_to_a_section() {
+        for example in registry::all() {
+            assert!(find_section(page_of(example)).is_some(), "{}: {}", example.id(), example.book);
+        }
+    }
+
+    #[test]
+    fn outline_is_consistent() {
+        for (i, chapter) in OUTLINE.iter().enumerate() {
+            assert_eq!(chapter.number as usize, i + 1);
+            for section in chapter.sections {
+                let prefix: String = format!("ch{:02}-", chapter.number);
+                assert!(section.page.starts_with(&prefix), "{}", section.page);
+            }
+        }
+    }
+
+    #[test]
+    fn statuses() {
+        let chapters: Vec<ChapterCoverage> = coverage();
+        assert_eq!(status_of(&chapters, "ch04-03-slices.html"), Status::Covered);
+        assert_eq!(status_of(&chapters, "ch03-04-comments.html"), Status::Missing);
+        assert_eq!(status_of(&chapters, "ch10-03-lifetime-syntax.html"), Status::Covered);
+        assert_eq!(chapters[3].percent(), 100.0);
+
+        let total: f64 = total_percent(&chapters);
+        assert!(total > 0.0 && total < 100.0);
+    }
+
+    #[test]
+    fn placeholder_counts_for_half() {
+        let section: &'static Section = &OUTLINE[0].sections[0];
+        let covered = |status: Status| SectionCoverage { section, status, examples: vec![] };
+        assert_eq!(percent(&[covered(Status::Covered), covered(Status::Partial)]), 75.0);
+        assert_eq!(percent(&[covered(Status::Missing)]), 0.0);
+        assert_eq!(percent(&[]), 0.0);
+    }
+}
@@ -0,0 +1,132 @@
+//! Captures what an example prints. Rust offers no stable way to redirect
+//! `println!` within a process, so the example is run by a child process of
+//! the running binary, through the hidden `__exec` command. A binary using
+//! `run_captured` must therefore pass that command on to `exec`.
+
+use std::env;
+use std::io::{self, Write};
+use std::process::{Command, ExitCode};
+use std::time::{Duration, Instant};
+
+use crate::registry::{self, Example};
+use crate::runner::{self, Failure, Outcome};
+
+
+/// Name of the hidden command that runs a single example in the child.
+pub const EXEC_COMMAND: &str = "__exec";
+
+
+#[derive(Clone, Debug)]
+pub struct Captured {
+    pub stdout: String,
+    pub outcome: Outcome,
+    /// How long the example ran, as timed by the child: unlike timing the
+    /// whole child, this leaves out the cost of starting a process. `None`
+    /// when the child exited before reporting it.
+    pub elapsed: Option<Duration>,
+}
+
+
+/// Runs `example` in a child process and collects its standard output.
+pub fn run_captured(example: &Example) -> io::Result<Captured> {
+    let output = Command::new(env::current_exe()?)
+        .arg(EXEC_COMMAND)
+        .arg(example.id())
+        .output()?;
+
+    let stdout: String = String::from_utf8_lossy(&output.stdout).into_owned();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let (elapsed, failure) = split_elapsed(&stderr);
+    let outcome: Outcome = if output.status.success() {
+        Outcome::Passed
+    } else {
+        Outcome::Failed(parse_failure(failure))
+    };
+
+    Ok(Captured { stdout, outcome, elapsed })
+}
+
+
+/// Entry point of the child: runs one example, then writes how long it took
+/// to stderr, followed on failure by the panic location and message, for
+/// `run_captured` to pick up.
+pub fn exec(id: &str) -> ExitCode {
+    let Some(example) = registry::find(id) else {
+        eprintln!("-\nno example named '{id}'");
+        return ExitCode::FAILURE;
+    };
+
+    let start = Instant::now();
+    let outcome: Outcome = runner::run_one(example);
+    let elapsed: Duration = start.elapsed();
+    io::stdout().flush().ok();
+    eprintln!("{EXEC_COMMAND} {}", elapsed.as_nanos());
+
+    match outcome {
+        Outcome::Failed(failure) => {
+            eprintln!("{}", failure.location.as_deref().unwrap_or("-"));
+            eprint!("{}", failure.message);
+            ExitCode::FAILURE
+        },
+        _ => ExitCode::SUCCESS,
+    }
+}
+
+
+// The line '__exec <nanoseconds>' and what follows it; anything before that
+// line was written to stderr by the example itself. Without that line, the
+// whole of `stderr` is taken as the failure.
+fn split_elapsed(stderr: &str) -> (Option<Duration>, &str) {
+    let prefix: String = format!("{EXEC_COMMAND} ");
+    let mut offset: usize = 0;
+    for line in stderr.split_inclusive('\n') {
+        offset += line.len();
+        let nanos: Option<u64> = line.strip_prefix(&prefix)
+            .and_then(|nanos| nanos.trim_end().parse().ok());
+        if let Some(nanos) = nanos {
+            return (Some(Duration::from_nanos(nanos)), &stderr[offset..]);
+        }
+    }
+    (None, stderr)
+}
+
+
+// first line is the location or '-', the rest is the message.
+fn parse_failure(stderr: &str) -> Failure {
+    let (location, message) = stderr.split_once('\n').unwrap_or((stderr, ""));
+    Failure {
+        message: message.to_string(),
+        location: match location.trim() {
+            "-" | "" => None,
+            location => Some(location.to_string()),
+        },
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failure_protocol() {
+        assert_eq!(parse_failure("src/a.rs:1:2\nboom\nbang"), Failure {
+            message: "boom\nbang".to_string(),
+            location: Some("src/a.rs:1:2".to_string()),
+        });
+        assert_eq!(parse_failure("-\nboom"), Failure {
+            message: "boom".to_string(),
+            location: None,
+        });
+    }
+
+    #[test]
+    fn elapsed_protocol() {
+        assert_eq!(split_elapsed("__exec 1500\n"), (Some(Duration::from_nanos(1500)), ""));
+        assert_eq!(
+            split_elapsed("printed by the example\n__exec 20\nsrc/a.rs:1:2\nboom"),
+            (Some(Duration::from_nanos(20)), "src/a.rs:1:2\nboom"),
+        );
+        assert_eq!(split_elapsed("-\nno example named 'x'"), (None, "-\nno example named 'x'"));
+    }
+}
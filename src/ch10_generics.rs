@@ -1,8 +1,24 @@
 
+//! Chapter 10: generic types, traits and lifetimes.
+//!
+//! See <https://doc.rust-lang.org/book/ch10-00-generics.html>.
+
+
+/// Functions, structs, 'impl' blocks and enums can be generic over types.
+///
+/// ```
+/// fn first_elem<T>(list: &[T]) -> Option<&T> {
+///     list.first()
+/// }
+///
+/// assert_eq!(first_elem(&[1, 2]), Some(&1));
+/// assert_eq!(first_elem(&["bob"]), Some(&"bob"));
+/// assert_eq!(first_elem::<char>(&[]), None);
+/// ```
 pub fn generic_type() {
     // we can implement a function of a generic type to reduce code duplication.
     fn _first_elem<T>(list: &[T]) -> Option<&T> {
-        list.get(0)
+        list.first()
     }
 
     // we can do the same thing for structs.
@@ -37,75 +53,482 @@ pub fn generic_type() {
 
 use std::cmp::Ordering;
 
-// Note that sometimes  we will want the generic type T to have certain 
-// features, such as being able to be compared when we make a function 
-// `fn largest(list: &[T])`. This is called a 'trait' in rust. 
-// In other languages, this may be called 'interface'. This is somewhat 
-// comparable to signature in ML.
-pub fn trait_for_generic() {
+/// a point that can be compared, see 'trait_for_generic'.
+///
+/// ```
+/// use syntax_examples::ch10_generics::Point;
+///
+/// assert!(Point{x: 3, y: 5} <= Point{x: 4, y: 4}); // compares 'x' first
+/// assert!(Point{x: 4, y: 3} < Point{x: 4, y: 4});
+/// ```
+pub struct Point<T: PartialOrd + PartialEq> {
+    pub x: T,
+    pub y: T,
+}
+
+
+// implement existing generics traits
+
+// impt<T: xxx> means "implement this only for type T that subscribes 
+// to trait xxx".
+impl<T: PartialOrd> PartialEq for Point<T> {
+    fn eq(&self, other: &Self) -> bool {
+        other.x == self.x && other.y == self.y
+    }
+}
 
-    struct Point<T: PartialOrd + PartialEq> {
-        x: T,
-        y: T,
+impl<T: PartialOrd> PartialOrd for Point<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        if self.x > other.x { Some(Ordering::Greater) } 
+        else if self.x < other.x{ Some(Ordering::Less) } 
+        else { self.y.partial_cmp(&other.y) }
     }
+}
 
 
-    // implement existing generics traits
+/// make and implement new traits; this can also be made generic.
+///
+/// ```
+/// use syntax_examples::ch10_generics::{Area, Circle, Square};
+///
+/// let shapes: Vec<Box<dyn Area>> = vec![
+///     Box::new(Circle{center_x: 0.0, center_y: 0.0, radius: 1.0}),
+///     Box::new(Square{center_x: 0.0, center_y: 0.0, side_len: 2.0}),
+/// ];
+/// let total: f32 = shapes.iter().map(|shape| shape.area()).sum();
+/// assert!(total > 7.0);
+/// ```
+pub struct Circle {
+    pub center_y: f32,
+    pub center_x: f32,
+    pub radius: f32,
+}
 
-    // impt<T: xxx> means "implement this only for type T that subscribes 
-    // to trait xxx".
-    impl<T: PartialOrd> PartialEq for Point<T> {
-        fn eq(&self, other: &Self) -> bool {
-            other.x == self.x && other.y == self.y
-        }
+pub struct Square {
+    pub center_x: f32,
+    pub center_y: f32, 
+    pub side_len: f32,
+}
+
+pub trait Area {
+    fn area(&self) -> f32;
+}
+
+impl Area for Circle {
+    fn area(&self) -> f32 {
+        self.radius * self.radius * std::f32::consts::PI
     }
+}
 
-    impl<T: PartialOrd> PartialOrd for Point<T> {
-        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-            if self.x > other.x { Some(Ordering::Greater) } 
-            else if self.x < other.x{ Some(Ordering::Less) } 
-            else { self.y.partial_cmp(&other.y) }
-        }
+impl Area for Square {
+    fn area(&self) -> f32 {
+        self.side_len * self.side_len
     }
+}
+
+
+/// Note that sometimes  we will want the generic type T to have certain 
+/// features, such as being able to be compared when we make a function 
+/// `fn largest(list: &[T])`. This is called a 'trait' in rust. 
+/// In other languages, this may be called 'interface'. This is somewhat 
+/// comparable to signature in ML.
+///
+/// ```
+/// fn largest<T: PartialOrd + Copy>(list: &[T]) -> T {
+///     let mut largest: T = list[0];
+///     for &item in list {
+///         if item > largest {
+///             largest = item;
+///         }
+///     }
+///     largest
+/// }
+///
+/// assert_eq!(largest(&[3, 7, 2]), 7);
+/// assert_eq!(largest(&['b', 'a']), 'b');
+/// ```
+///
+/// Without the bound, 'T' cannot be compared:
+///
+/// ```compile_fail,E0369
+/// fn largest<T: Copy>(list: &[T]) -> T {
+///     if list[0] > list[1] { list[0] } else { list[1] }
+/// }
+/// ```
+pub fn trait_for_generic() {
 
     let p1 = Point{x: 3, y: 5};
     let p2 = Point{x: 4, y: 4};
 
     assert!(p1 <= p2);
 
+    let c = Circle{center_x: 0.0, center_y: 0.0, radius: 1.0};
+    let s = Square{center_x: 0.0, center_y: 0.0, side_len: 2.0};
+    assert!(c.area() < s.area());
     
-    // make and implement new traits; this can also be made generic.
-    struct Circle {
-        _center_y: f32,
-        _center_x: f32,
-        radius: f32,
+}
+
+
+/// The longer of two strings, borrowed from one of them.
+///
+/// ```
+/// use syntax_examples::ch10_generics::longest;
+///
+/// let alice: String = String::from("alice");
+/// {
+///     let bob: String = String::from("bob");
+///     assert_eq!(longest(&alice, &bob), "alice"); // used while both live
+/// }
+/// ```
+pub fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() > y.len() { x } else { y }
+}
+
+/// Lifetimes are another kind of generic parameter: rather than a type, they
+/// describe how long references stay valid, so that the compiler can reject
+/// references that outlive their data. When a function returns a reference
+/// borrowed from one of several arguments, the compiler cannot tell which one
+/// without an annotation (see compile_fail/ch10_generics/missing_lifetime.rs).
+///
+/// 'longest<'a>' reads: for some lifetime 'a, both arguments live at least as
+/// long as 'a, and so does the result. In practice 'a becomes the shorter of
+/// the two lifetimes, so the result cannot be used once either argument is
+/// gone (see compile_fail/ch10_generics/result_outlives_argument.rs).
+pub fn lifetime() {
+
+    let s1: String = String::from("bob likes alice");
+    let result: &str;
+    {
+        let s2: &str = "alice likes rust"; // a literal lives for the whole program
+        result = longest(s1.as_str(), s2);
     }
+    assert_eq!(result, "alice likes rust"); // fine: both still live here
+
+
+    // annotations do not change how long anything lives: returning a
+    // reference to a local variable is still rejected, whatever the signature
+    // says (see compile_fail/ch10_generics/return_local_reference.rs).
+    // Return an owned value instead.
+    fn joined(x: &str, y: &str) -> String {
+        let result: String = format!("{x}{y}");
+        result
+    }
+
+    assert_eq!(joined("bob", "alice"), "bobalice");
+}
+
+
+/// A struct holding a reference needs a lifetime parameter too (see
+/// compile_fail/ch10_generics/struct_missing_lifetime.rs): an instance of
+/// 'ImportantExcerpt' cannot outlive the text its 'part' borrows.
+///
+/// ```compile_fail,E0597
+/// use syntax_examples::ch10_generics::ImportantExcerpt;
+///
+/// let excerpt: ImportantExcerpt;
+/// {
+///     let novel: String = String::from("Call me Ishmael. Some years ago...");
+///     excerpt = ImportantExcerpt{part: novel.split('.').next().unwrap()};
+/// }
+/// println!("{}", excerpt.part); // 'novel' is gone already
+/// ```
+pub struct ImportantExcerpt<'a> {
+    pub part: &'a str,
+}
+
+pub fn struct_lifetime() {
+
+    let novel: String = String::from("Call me Ishmael. Some years ago...");
+    let first_sentence: &str = novel.split('.').next().unwrap();
+
+    let excerpt = ImportantExcerpt{part: first_sentence};
+    assert_eq!(excerpt.part, "Call me Ishmael");
 
-    struct Square {
-        _center_x: f32,
-        _center_y: f32, 
-        side_len: f32,
+    // 'excerpt' must be dropped before 'novel', which holds here as they are
+    // dropped in reverse order of declaration.
+}
+
+
+/// Most of the time the lifetimes of a signature can be left out, and the
+/// compiler fills them in with three rules, called the elision rules:
+///  1. each reference parameter gets its own lifetime parameter;
+///  2. if there is exactly one input lifetime, it is assigned to every output
+///     lifetime;
+///  3. if one of the parameters is '&self' or '&mut self', the lifetime of
+///     'self' is assigned to every output lifetime (see 'impl_lifetime').
+///
+/// When the rules leave an output lifetime undecided, as in 'longest', the
+/// annotation is mandatory.
+pub fn lifetime_elision() {
+
+    // rule 1: 'fn total_len(x: &str, y: &str)' is understood as
+    // 'fn total_len<'a, 'b>(x: &'a str, y: &'b str)'.
+    fn total_len(x: &str, y: &str) -> usize {
+        x.len() + y.len()
     }
+    assert_eq!(total_len("bob", "alice"), 8);
 
-    trait Area {
-        fn area(&self) -> f32;
+
+    // rule 2: 'fn first_word(s: &str) -> &str' is understood as
+    // 'fn first_word<'a>(s: &'a str) -> &'a str'. The output can therefore
+    // only borrow from 's', never from a local variable
+    // (see compile_fail/ch10_generics/return_local_reference.rs).
+    fn first_word(s: &str) -> &str {
+        s.split(' ').next().unwrap_or("")
     }
+    assert_eq!(first_word("bob likes alice"), "bob");
 
-    impl Area for Circle {
-        fn area(&self) -> f32 {
-            self.radius * self.radius * 3.14
-        }
+
+    // rule 3: the output borrows from 'self', see 'ImportantExcerpt::part'.
+    let novel: String = String::from("Call me Ishmael.");
+    let excerpt = ImportantExcerpt{part: &novel};
+    assert_eq!(excerpt.part(), "Call me Ishmael.");
+}
+
+
+// Lifetime names of a struct are declared after 'impl', like generic types,
+// and used after the struct name (see
+// compile_fail/ch10_generics/impl_missing_lifetime.rs).
+impl<'a> ImportantExcerpt<'a> {
+
+    // rule 1 alone is enough when nothing is returned by reference.
+    pub fn level(&self) -> i32 {
+        3
     }
 
-    impl Area for Square {
-        fn area(&self) -> f32 {
-            self.side_len * self.side_len
-        }
+    // rule 3: the result borrows from 'self', not from 'announcement'.
+    pub fn announce_and_return_part(&self, announcement: &str) -> &str {
+        println!("Attention please: {announcement}");
+        self.part
+    }
+
+    // the elided '&self' above is really '&'b ImportantExcerpt<'a>', where 'b
+    // is shorter than 'a. Naming 'a returns the longer lifetime of the text
+    // itself, so the result may outlive the excerpt.
+    pub fn part(&self) -> &'a str {
+        self.part
     }
-    
 }
 
+pub fn impl_lifetime() {
 
-pub fn lifetime() {
-    
-}
\ No newline at end of file
+    let novel: String = String::from("Call me Ishmael. Some years ago...");
+    let part: &str;
+    {
+        let excerpt = ImportantExcerpt{part: novel.split('.').next().unwrap()};
+        assert_eq!(excerpt.level(), 3);
+        assert_eq!(excerpt.announce_and_return_part("attention!"), "Call me Ishmael");
+        part = excerpt.part(); // borrows from 'novel', not from 'excerpt'
+    }
+    assert_eq!(part, "Call me Ishmael");
+}
+
+
+/// The 'static lifetime is the whole run of the program. String literals have
+/// it, since they are stored in the binary itself, and so do constants. A
+/// reference to a temporary value cannot be 'static
+/// (see compile_fail/ch10_generics/temporary_as_static.rs).
+///
+/// ```
+/// fn pick(first: bool) -> &'static str {
+///     if first { "bob" } else { "alice" }
+/// }
+///
+/// let name: &str;
+/// {
+///     name = pick(false);
+/// }
+/// assert_eq!(name, "alice"); // outlives the block that produced it
+/// ```
+pub fn static_lifetime() {
+
+    let s: &'static str = "I have a static lifetime.";
+
+    const GREETING: &str = "hello"; // constants are implicitly &'static
+    static NAMES: [&str; 2] = ["bob", "alice"];
+
+    fn first_name() -> &'static str {
+        NAMES[0]
+    }
+
+    assert!(s.ends_with("lifetime."));
+    assert_eq!(format!("{GREETING} {}", first_name()), "hello bob");
+
+
+    // a reference to a 'static' item is 'static too.
+    let names: &'static [&str] = &NAMES;
+    assert_eq!(names, ["bob", "alice"]);
+
+    // 'String::leak' and 'Box::leak' also give a 'static reference, to a heap
+    // value that is never freed: every call leaks memory for good, so they
+    // only suit values built once and kept for the whole run.
+}
+
+
+/// Generic parameters, trait bounds and lifetimes can appear together.
+///
+/// ```
+/// use syntax_examples::ch10_generics::longest_with_an_announcement;
+///
+/// assert_eq!(longest_with_an_announcement("bob", "alice", 42), "alice");
+/// ```
+pub fn longest_with_an_announcement<'a, T>(x: &'a str, y: &'a str, announcement: T) -> &'a str
+where
+    T: std::fmt::Display,
+{
+    println!("Announcement! {announcement}");
+    if x.len() > y.len() { x } else { y }
+}
+
+/// A bound 'T: 'a' requires every reference inside 'T' to outlive 'a. With
+/// 'T: 'static', 'T' holds no borrowed data at all (or only 'static data), so
+/// it can be kept for as long as needed, eg. in a `Box<dyn Display>` (see
+/// compile_fail/ch10_generics/missing_static_bound.rs).
+pub fn lifetime_bounds() {
+    use std::fmt::Display;
+
+    // a wrapper around a reference to any 'T' that outlives 'a.
+    struct Ref<'a, T: 'a> {
+        value: &'a T,
+    }
+
+    let n: i32 = 233;
+    let r = Ref{value: &n};
+    assert_eq!(*r.value, 233);
+
+
+    fn boxed<T: Display + 'static>(x: T) -> Box<dyn Display> {
+        Box::new(x)
+    }
+
+    assert_eq!(boxed(666).to_string(), "666");
+    assert_eq!(boxed("a literal").to_string(), "a literal"); // &'static str
+
+
+    // 'dyn Trait + 'a' is the same idea for trait objects.
+    fn describe<'a>(items: &'a [i32]) -> Box<dyn Iterator<Item = &'a i32> + 'a> {
+        Box::new(items.iter().filter(|n| **n > 0))
+    }
+
+    let items: Vec<i32> = vec![-1, 2, 3];
+    assert_eq!(describe(&items).count(), 2);
+
+    assert_eq!(longest_with_an_announcement("bob", "alice", "today"), "alice");
+}
+
+use crate::registry::{example, Difficulty, Example};
+
+pub const EXAMPLES: &[Example] = &[
+    example!(10, generic_type)
+        .summary("Generic functions, structs, impl blocks and enums.")
+        .tags(&["generics"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch5_struct::struct_method", "ch6_enum::enum_syntax"])
+        .book("ch10-01-syntax.html"),
+    example!(10, trait_for_generic)
+        .summary("Trait bounds, implementing std traits and defining new ones.")
+        .tags(&["generics", "traits"])
+        .difficulty(Difficulty::Advanced)
+        .requires(&["ch10_generics::generic_type"])
+        .shows(&["Point", "Area", "Circle", "Square"])
+        .book("ch10-02-traits.html"),
+    example!(10, lifetime)
+        .summary("'longest<'a>': tying a returned reference to its arguments.")
+        .tags(&["generics", "lifetimes", "references"])
+        .difficulty(Difficulty::Advanced)
+        .requires(&["ch10_generics::generic_type", "ch4_ownership::slice_ownership"])
+        .shows(&["longest"])
+        .book("ch10-03-lifetime-syntax.html#lifetime-annotations-in-function-signatures"),
+    example!(10, struct_lifetime)
+        .summary("A struct holding a reference cannot outlive the data it borrows.")
+        .tags(&["lifetimes", "structs", "references"])
+        .difficulty(Difficulty::Advanced)
+        .requires(&["ch10_generics::lifetime", "ch5_struct::struct_syntax"])
+        .shows(&["ImportantExcerpt"])
+        .book("ch10-03-lifetime-syntax.html#lifetime-annotations-in-struct-definitions"),
+    example!(10, lifetime_elision)
+        .summary("The three rules that let most signatures leave lifetimes out.")
+        .tags(&["lifetimes", "functions"])
+        .difficulty(Difficulty::Advanced)
+        .requires(&["ch10_generics::struct_lifetime"])
+        .shows(&["ImportantExcerpt"])
+        .book("ch10-03-lifetime-syntax.html#lifetime-elision"),
+    example!(10, impl_lifetime)
+        .summary("Lifetimes on 'impl' blocks and methods returning references.")
+        .tags(&["lifetimes", "methods"])
+        .difficulty(Difficulty::Advanced)
+        .requires(&["ch10_generics::lifetime_elision", "ch5_struct::struct_method"])
+        .shows(&["ImportantExcerpt"])
+        .book("ch10-03-lifetime-syntax.html#lifetime-annotations-in-method-definitions"),
+    example!(10, static_lifetime)
+        .summary("The 'static lifetime: literals, constants and statics.")
+        .tags(&["lifetimes", "strings"])
+        .difficulty(Difficulty::Advanced)
+        .requires(&["ch10_generics::lifetime"])
+        .book("ch10-03-lifetime-syntax.html#the-static-lifetime"),
+    example!(10, lifetime_bounds)
+        .summary("'T: 'a' and 'T: 'static' bounds next to trait bounds.")
+        .tags(&["lifetimes", "generics", "traits"])
+        .difficulty(Difficulty::Advanced)
+        .requires(&["ch10_generics::trait_for_generic", "ch10_generics::static_lifetime"])
+        .shows(&["longest_with_an_announcement"])
+        .book("ch10-03-lifetime-syntax.html#generic-type-parameters-trait-bounds-and-lifetimes-together"),
+];
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn longest_of_equal_lengths() {
+        assert_eq!(longest("bob", "eve"), "eve"); // ties go to 'y'
+        assert_eq!(longest("", ""), "");
+        assert_eq!(longest("alice", ""), "alice");
+    }
+
+    #[test]
+    fn part_outlives_excerpt() {
+        let novel: String = String::from("Call me Ishmael. Some years ago...");
+        let part: &str = {
+            let excerpt = ImportantExcerpt{part: &novel[..15]};
+            excerpt.part()
+        };
+        assert_eq!(part, "Call me Ishmael");
+    }
+
+    #[test]
+    fn announcement_is_not_returned() {
+        let excerpt = ImportantExcerpt{part: ""};
+        assert_eq!(excerpt.announce_and_return_part("hello"), "");
+        assert_eq!(longest_with_an_announcement("", "", 'x'), "");
+    }
+
+    #[test]
+    fn points_compare_by_x_then_y() {
+        assert!(Point{x: 3, y: 5} < Point{x: 4, y: 4});
+        assert!(Point{x: 4, y: 3} < Point{x: 4, y: 4});
+        assert!(Point{x: 4, y: 4} == Point{x: 4, y: 4});
+        assert!(Point{x: 'b', y: 'a'} > Point{x: 'a', y: 'z'});
+    }
+
+    #[test]
+    fn nan_points_are_unordered() {
+        let p = Point{x: 1.0, y: f64::NAN};
+        assert_eq!(p.partial_cmp(&Point{x: 1.0, y: 0.0}), None);
+        assert!(p != Point{x: 1.0, y: f64::NAN});
+        assert_eq!(p.partial_cmp(&Point{x: 2.0, y: 0.0}), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn areas() {
+        let c = Circle{center_x: 0.0, center_y: 0.0, radius: 1.0};
+        let s = Square{center_x: 0.0, center_y: 0.0, side_len: 2.0};
+        assert!((c.area() - std::f32::consts::PI).abs() < 1e-6);
+        assert_eq!(s.area(), 4.0);
+
+        let shapes: [&dyn Area; 2] = [&c, &s];
+        let total: f32 = shapes.iter().map(|shape| shape.area()).sum();
+        assert!(total > 7.0);
+    }
+}
@@ -0,0 +1,285 @@
+//! Chapter 11: writing, running and organizing tests.
+//!
+//! The examples below call the code under test the way its tests do; the
+//! tests themselves are in the 'tests' module at the bottom of this file, and
+//! the integration tests in the 'tests/' directory of the repository.
+//!
+//! See <https://doc.rust-lang.org/book/ch11-00-testing.html>.
+
+
+/// The function under test in most of the examples.
+pub fn add_two(a: u64) -> u64 {
+    internal_adder(a, 2)
+}
+
+
+// private: unit tests can call it, integration tests cannot.
+fn internal_adder(left: u64, right: u64) -> u64 {
+    left + right
+}
+
+
+pub fn greeting(name: &str) -> String {
+    format!("Hello {name}!")
+}
+
+
+/// A number between 1 and 100, checked on construction.
+///
+/// ```should_panic
+/// use syntax_examples::ch11_testing::Guess;
+///
+/// Guess::new(200); // panics: the value is out of range
+/// ```
+pub struct Guess {
+    value: i32,
+}
+
+
+impl Guess {
+    pub fn new(value: i32) -> Guess {
+        if value < 1 {
+            panic!("Guess value must be greater than or equal to 1, got {value}.");
+        } else if value > 100 {
+            panic!("Guess value must be less than or equal to 100, got {value}.");
+        }
+        Guess { value }
+    }
+
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+}
+
+
+/// A test is a function annotated with `#[test]`; it fails when it panics,
+/// which is what the assertion macros do when their condition does not hold.
+///
+/// ```ignore
+/// #[cfg(test)] // compiled for 'cargo test' only
+/// mod tests {
+///     use super::*;
+///
+///     #[test]
+///     fn it_adds_two() {
+///         assert_eq!(add_two(2), 4);
+///     }
+/// }
+/// ```
+pub fn assertions() {
+
+    // 'assert!' checks a bool; 'assert_eq!' and 'assert_ne!' compare two
+    // values and print both when they fail, so they need 'PartialEq' and
+    // 'Debug'. By convention the computed value comes first.
+    assert!(add_two(0) > 0);
+    assert_eq!(add_two(2), 4);
+    assert_ne!(add_two(2), 2);
+
+
+    // any extra arguments are a format string and its values, printed along
+    // with the failure:
+    //   'Greeting did not contain name, value was `Hello!`'
+    let result: String = greeting("Carol");
+    assert!(
+        result.contains("Carol"),
+        "Greeting did not contain name, value was `{result}`"
+    );
+}
+
+
+/// `#[should_panic]` makes a test pass only if it panics; 'expected' also
+/// requires the panic message to contain the given text, so the test cannot
+/// pass by panicking for another reason.
+///
+/// ```ignore
+/// #[test]
+/// #[should_panic(expected = "less than or equal to 100")]
+/// fn greater_than_100() {
+///     Guess::new(200);
+/// }
+/// ```
+pub fn expected_panics() {
+
+    assert_eq!(Guess::new(50).value(), 50);
+
+    // what '#[should_panic(expected = ...)]' checks, by hand: catch the
+    // panic and look at its message.
+    let payload = std::panic::catch_unwind(|| Guess::new(200)).err().unwrap();
+    let message: &String = payload.downcast_ref::<String>().unwrap();
+    assert!(message.contains("less than or equal to 100"));
+}
+
+
+/// A test may return a 'Result' instead of panicking: 'Err' fails it, and
+/// '?' can be used in its body. Such tests cannot use `#[should_panic]`;
+/// assert 'value.is_err()' instead.
+///
+/// ```ignore
+/// #[test]
+/// fn it_works() -> Result<(), String> {
+///     if 2 + 2 == 4 {
+///         Ok(())
+///     } else {
+///         Err(String::from("two plus two does not equal four"))
+///     }
+/// }
+/// ```
+pub fn result_tests() {
+
+    fn it_works() -> Result<(), String> {
+        let n: u64 = "2".parse::<u64>().map_err(|e| e.to_string())?;
+        if add_two(n) == 4 {
+            Ok(())
+        } else {
+            Err(String::from("two plus two does not equal four"))
+        }
+    }
+
+    fn it_fails() -> Result<(), String> {
+        let _n: u64 = "two".parse::<u64>().map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    assert_eq!(it_works(), Ok(()));
+    assert_eq!(it_fails(), Err(String::from("invalid digit found in string")));
+}
+
+
+/// Sums 0..n one number at a time, slowly on purpose.
+pub fn expensive_sum(n: u64) -> u64 {
+    let mut total: u64 = 0;
+    for i in 0..n {
+        total += i;
+    }
+    total
+}
+
+
+/// 'cargo test' runs tests in parallel and hides what passing tests print:
+///  * 'cargo test add' runs only the tests whose name contains "add";
+///  * 'cargo test -- --test-threads=1' runs them one at a time;
+///  * 'cargo test -- --show-output' shows the output of passing tests;
+///  * `#[ignore]` skips a test unless 'cargo test -- --ignored' (only the
+///    ignored ones) or '-- --include-ignored' (all of them) is given.
+pub fn running_tests() {
+
+    // the fast check runs every time, the slow one is '#[ignore]'d below.
+    assert_eq!(expensive_sum(10), 45);
+}
+
+
+/// Unit tests sit next to the code in a `#[cfg(test)]` module, and can test
+/// private functions since the module is a child of the code's. Integration
+/// tests sit in the 'tests/' directory; each file there is a separate crate
+/// that only sees the public API, and shared helpers go in
+/// 'tests/common/mod.rs' so that cargo does not run them as a test file.
+///
+/// ```compile_fail,E0603
+/// // an integration test cannot reach a private function
+/// syntax_examples::ch11_testing::internal_adder(2, 2);
+/// ```
+pub fn test_organization() {
+    assert_eq!(internal_adder(2, 2), add_two(2));
+}
+
+
+use crate::registry::{example, Difficulty, Example};
+
+pub const EXAMPLES: &[Example] = &[
+    example!(11, assertions)
+        .summary("'assert!', 'assert_eq!', 'assert_ne!' and custom failure messages.")
+        .tags(&["testing", "panic"])
+        .difficulty(Difficulty::Beginner)
+        .requires(&["ch3_control_flow::function_call", "ch8_collections::string_syntax"])
+        .shows(&["add_two", "greeting"])
+        .book("ch11-01-writing-tests.html"),
+    example!(11, expected_panics)
+        .summary("Checking that code panics with '#[should_panic(expected = ...)]'.")
+        .tags(&["testing", "panic"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch11_testing::assertions", "ch9_errors::panic_syntax"])
+        .shows(&["Guess"])
+        .book("ch11-01-writing-tests.html#checking-for-panics-with-should_panic"),
+    example!(11, result_tests)
+        .summary("Tests that return a Result and use '?'.")
+        .tags(&["testing", "result", "error-handling"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch11_testing::assertions", "ch9_errors::err_propagation"])
+        .shows(&["add_two"])
+        .book("ch11-01-writing-tests.html#using-resultt-e-in-tests"),
+    example!(11, running_tests)
+        .summary("Filtering, '--test-threads', '--show-output' and '#[ignore]'.")
+        .tags(&["testing"])
+        .difficulty(Difficulty::Beginner)
+        .requires(&["ch11_testing::assertions"])
+        .shows(&["expensive_sum"])
+        .book("ch11-02-running-tests.html"),
+    example!(11, test_organization)
+        .summary("Unit tests of private functions, integration tests in 'tests/'.")
+        .tags(&["testing", "modules", "visibility"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch11_testing::assertions", "ch7_modules::visibility"])
+        .shows(&["add_two", "internal_adder"])
+        .book("ch11-03-test-organization.html"),
+];
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_two() {
+        assert_eq!(add_two(2), 4, "2 + 2 should be 4");
+        assert_eq!(add_two(u64::MAX - 2), u64::MAX);
+    }
+
+    // a unit test can call a private function.
+    #[test]
+    fn internal() {
+        assert_eq!(internal_adder(2, 2), 4);
+    }
+
+    #[test]
+    fn greeting_contains_name() {
+        let result: String = greeting("Carol");
+        assert!(
+            result.contains("Carol"),
+            "Greeting did not contain name, value was `{result}`"
+        );
+    }
+
+    #[test]
+    fn it_works() -> Result<(), String> {
+        let n: u64 = "2".parse::<u64>().map_err(|e| e.to_string())?;
+        if add_two(n) == 4 {
+            Ok(())
+        } else {
+            Err(String::from("two plus two does not equal four"))
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "less than or equal to 100")]
+    fn greater_than_100() {
+        Guess::new(200);
+    }
+
+    #[test]
+    #[should_panic(expected = "greater than or equal to 1")]
+    fn less_than_1() {
+        Guess::new(0);
+    }
+
+    #[test]
+    fn guess_bounds_are_inclusive() {
+        assert_eq!(Guess::new(1).value(), 1);
+        assert_eq!(Guess::new(100).value(), 100);
+    }
+
+    #[test]
+    #[ignore = "slow; run with 'cargo test -- --ignored'"]
+    fn expensive_test() {
+        assert_eq!(expensive_sum(100_000_000), 4_999_999_950_000_000);
+    }
+}
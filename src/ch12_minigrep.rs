@@ -0,0 +1,223 @@
+//! Chapter 12: an I/O project, 'minigrep'.
+//!
+//! The project itself is the 'minigrep' crate of this workspace, in the
+//! 'minigrep/' directory; run it with 'cargo run -p minigrep -- to
+//! minigrep/poem.txt'. The examples below use it as a library.
+//!
+//! See <https://doc.rust-lang.org/book/ch12-00-an-io-project.html>.
+
+use std::env;
+
+use minigrep::Config;
+
+
+/// 'std::env::args()' yields the program name, then the arguments; minigrep
+/// collects them into a vector and builds a 'Config' from it, returning an
+/// 'Err' rather than panicking when some are missing.
+///
+/// ```
+/// use minigrep::Config;
+///
+/// let args: Vec<String> = vec!["minigrep".into(), "to".into(), "poem.txt".into()];
+/// assert_eq!(Config::build(&args).unwrap().query, "to");
+/// ```
+pub fn accept_arguments() {
+
+    // what 'std::env::args().collect()' returns for 'minigrep to poem.txt'.
+    let args: Vec<String> = vec![
+        String::from("minigrep"),
+        String::from("to"),
+        String::from("poem.txt"),
+    ];
+    let config: Config = Config::build(&args).unwrap();
+    assert_eq!(config.query, "to");
+    assert_eq!(config.file_path, "poem.txt");
+
+    assert_eq!(Config::build(&args[..2]), Err("not enough arguments"));
+}
+
+
+/// 'fs::read_to_string' reads a whole file into a String.
+pub fn read_file() {
+    let contents: String = std::fs::read_to_string("poem.txt").unwrap();
+    assert!(contents.starts_with("I'm nobody! Who are you?"));
+    assert_eq!(contents.lines().count(), 9);
+}
+
+
+/// 'main' only parses the arguments and calls 'minigrep::run', which returns
+/// a 'Result' instead of panicking; 'main' then reports errors and exits with
+/// a non-zero code. Keeping the logic in 'lib.rs' makes it testable.
+pub fn error_handling() {
+
+    let config = Config {
+        query: String::from("frog"),
+        file_path: String::from("poem.txt"),
+        ignore_case: false,
+    };
+
+    // 'run' returns 'Box<dyn Error>', so '?' converts any error into it.
+    // 'main' would print it with 'eprintln!("Application error: {e}")'.
+    let error: Box<dyn std::error::Error> = minigrep::run(config).unwrap_err();
+    let io_error: &std::io::Error = error.downcast_ref::<std::io::Error>().unwrap();
+    assert_eq!(io_error.kind(), std::io::ErrorKind::NotFound);
+}
+
+
+/// 'search' is written test first: its tests live in 'minigrep/src/lib.rs'.
+/// The returned lines borrow from 'contents', hence the lifetime.
+///
+/// ```
+/// let contents: &str = "Rust:\nsafe, fast, productive.\nPick three.";
+/// assert_eq!(minigrep::search("duct", contents), vec!["safe, fast, productive."]);
+/// ```
+pub fn search_function() {
+    let contents: &str = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Duct tape.";
+
+    assert_eq!(minigrep::search("duct", contents), vec!["safe, fast, productive."]);
+    assert!(minigrep::search("rust", contents).is_empty());
+}
+
+
+/// Setting the 'IGNORE_CASE' environment variable makes 'Config::build' set
+/// 'ignore_case', so that 'run' calls 'search_case_insensitive':
+///
+/// ```text
+/// IGNORE_CASE=1 cargo run -p minigrep -- to minigrep/poem.txt
+/// ```
+pub fn ignore_case() {
+
+    // the sandbox starts with 'IGNORE_CASE' unset, and restores it afterwards.
+    let args: Vec<String> = vec![
+        String::from("minigrep"),
+        String::from("rUsT"),
+        String::from("poem.txt"),
+    ];
+    assert!(!Config::build(&args).unwrap().ignore_case);
+
+    env::set_var("IGNORE_CASE", "1"); // any value will do
+    let config: Config = Config::build(&args).unwrap();
+    assert!(config.ignore_case);
+    env::remove_var("IGNORE_CASE");
+
+
+    // what 'run' then searches with.
+    let contents: &str = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Trust me.";
+
+    assert_eq!(minigrep::search_case_insensitive(&config.query, contents), vec!["Rust:", "Trust me."]);
+    assert!(minigrep::search(&config.query, contents).is_empty());
+}
+
+
+/// 'println!' writes to stdout and 'eprintln!' to stderr: minigrep prints the
+/// results to the former and its errors to the latter, so that redirecting
+/// the output to a file ('> output.txt') keeps the errors on screen.
+///
+/// ```text
+/// $ cargo run -p minigrep > output.txt
+/// Problem parsing arguments: not enough arguments
+/// ```
+///
+/// This example only prints the results, which its golden file records;
+/// minigrep/tests/cli.rs checks that the binary prints its errors to stderr.
+pub fn stderr_output() {
+
+    // 'minigrep to poem.txt': the matching lines go to stdout.
+    let config = Config {
+        query: String::from("to"),
+        file_path: String::from("poem.txt"),
+        ignore_case: false,
+    };
+    minigrep::run(config).unwrap();
+
+
+    // 'minigrep' alone: 'main' would print this error with 'eprintln!' and
+    // exit with code 1, having printed nothing on stdout.
+    let args: Vec<String> = vec![String::from("minigrep")];
+    assert_eq!(Config::build(&args), Err("not enough arguments"));
+}
+
+
+use crate::registry::{example, Difficulty, Example};
+use crate::sandbox::Setup;
+
+// the examples reading "poem.txt" run in a sandbox holding minigrep's poem;
+// 'ignore_case' sets IGNORE_CASE in one where the variable starts unset.
+const POEM_PRESENT: Setup = Setup::FilePresent {
+    path: "poem.txt",
+    contents: include_str!("../minigrep/poem.txt"),
+};
+const POEM_ABSENT: Setup = Setup::FileAbsent { path: "poem.txt" };
+const IGNORE_CASE_UNSET: Setup = Setup::VarUnset { name: "IGNORE_CASE" };
+
+pub const EXAMPLES: &[Example] = &[
+    example!(12, accept_arguments)
+        .summary("Parsing the command line into a Config.")
+        .tags(&["strings", "vectors", "error-handling"])
+        .difficulty(Difficulty::Beginner)
+        .requires(&["ch8_collections::vector_syntax", "ch9_errors::match_on_error"])
+        .book("ch12-01-accepting-command-line-arguments.html"),
+    example!(12, read_file)
+        .summary("Reading a whole file into a String.")
+        .tags(&["files", "strings"])
+        .difficulty(Difficulty::Beginner)
+        .requires(&["ch12_minigrep::accept_arguments"])
+        .book("ch12-02-reading-a-file.html")
+        .setup(POEM_PRESENT),
+    example!(12, error_handling)
+        .summary("A 'run' function returning 'Box<dyn Error>', and a thin 'main'.")
+        .tags(&["error-handling", "result", "files"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch12_minigrep::read_file", "ch9_errors::err_propagation"])
+        .book("ch12-03-improving-error-handling-and-modularity.html")
+        .setup(POEM_ABSENT),
+    example!(12, search_function)
+        .summary("A test-driven 'search' returning lines borrowed from its input.")
+        .tags(&["testing", "lifetimes", "strings"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch12_minigrep::error_handling", "ch11_testing::assertions", "ch10_generics::lifetime"])
+        .book("ch12-04-testing-the-librarys-functionality.html"),
+    example!(12, ignore_case)
+        .summary("Case-insensitive search switched on by the IGNORE_CASE variable.")
+        .tags(&["strings"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch12_minigrep::search_function"])
+        .book("ch12-05-working-with-environment-variables.html")
+        .setup(IGNORE_CASE_UNSET),
+    example!(12, stderr_output)
+        .summary("Results on stdout, errors on stderr.")
+        .tags(&["files", "error-handling"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch12_minigrep::ignore_case"])
+        .book("ch12-06-writing-to-stderr-instead-of-stdout.html")
+        .setup(POEM_PRESENT),
+];
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sandbox::Sandbox;
+
+    #[test]
+    fn ignore_case_unsets_the_variable_again() {
+        let _sandbox = Sandbox::enter(IGNORE_CASE_UNSET).unwrap();
+        ignore_case();
+        assert!(env::var_os("IGNORE_CASE").is_none());
+    }
+
+    #[test]
+    fn search_poem() {
+        let poem: &str = include_str!("../minigrep/poem.txt");
+        assert_eq!(minigrep::search("frog", poem), vec!["How public, like a frog"]);
+        assert_eq!(minigrep::search_case_insensitive("HOW", poem).len(), 2);
+    }
+}
@@ -0,0 +1,345 @@
+//! Chapter 13: closures, anonymous functions that capture their environment.
+//!
+//! See <https://doc.rust-lang.org/book/ch13-01-closures.html>.
+
+use std::thread;
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShirtColor {
+    Red,
+    Blue,
+}
+
+
+/// The shirts in stock of a company giving one away to each customer.
+pub struct Inventory {
+    pub shirts: Vec<ShirtColor>,
+}
+
+
+impl Inventory {
+    /// The customer's favourite color if they have one, else the color with
+    /// the most shirts in stock.
+    pub fn giveaway(&self, user_preference: Option<ShirtColor>) -> ShirtColor {
+        // the closure captures 'self' by immutable reference; it is only called
+        // when 'user_preference' is None.
+        user_preference.unwrap_or_else(|| self.most_stocked())
+    }
+
+    fn most_stocked(&self) -> ShirtColor {
+        let num_red: usize = self.shirts.iter().filter(|&&color| color == ShirtColor::Red).count();
+        let num_blue: usize = self.shirts.len() - num_red;
+        if num_red > num_blue {
+            ShirtColor::Red
+        } else {
+            ShirtColor::Blue
+        }
+    }
+}
+
+
+/// Closures are written '|params| body'. Unlike functions, their parameter
+/// and return types are usually inferred from how they are used; once
+/// inferred, they are fixed.
+///
+/// ```
+/// fn add_one_v1(x: u32) -> u32 { x + 1 }
+/// let add_one_v2 = |x: u32| -> u32 { x + 1 };
+/// let add_one_v3 = |x| { x + 1 };
+/// let add_one_v4 = |x| x + 1;
+///
+/// assert_eq!(add_one_v1(1), add_one_v2(1));
+/// assert_eq!(add_one_v3(1), add_one_v4(1));
+/// ```
+///
+/// ```compile_fail,E0308
+/// let example_closure = |x| x;
+/// let s = example_closure(String::from("hello")); // 'x' is a String now
+/// let n = example_closure(5);
+/// ```
+pub fn closure_syntax() {
+
+    let store = Inventory {
+        shirts: vec![ShirtColor::Blue, ShirtColor::Red, ShirtColor::Blue],
+    };
+    assert_eq!(store.giveaway(Some(ShirtColor::Red)), ShirtColor::Red);
+    assert_eq!(store.giveaway(None), ShirtColor::Blue);
+
+
+    // annotations are optional, and the body may be a single expression.
+    let add_one_v2 = |x: u32| -> u32 { x + 1 };
+    let add_one_v4 = |x| x + 1;
+    assert_eq!(add_one_v2(1), 2);
+    assert_eq!(add_one_v4(1), 2);
+
+
+    // each closure has its own, unnameable type, fixed by its first use
+    // (see compile_fail/ch13_closures/inferred_type_mismatch.rs).
+    let example_closure = |x| x;
+    let s: String = example_closure(String::from("hello"));
+    assert_eq!(s, "hello");
+}
+
+
+/// A closure captures the variables it uses the way its body needs them:
+/// by immutable reference, by mutable reference, or by value. 'move' forces
+/// capture by value.
+///
+/// ```
+/// let mut list: Vec<i32> = vec![1, 2, 3];
+/// let mut borrows_mutably = || list.push(7);
+/// borrows_mutably(); // 'list' cannot be used until the last call
+/// assert_eq!(list, [1, 2, 3, 7]);
+/// ```
+pub fn capture_modes() {
+
+    // immutable borrow: 'list' can still be read while the closure exists.
+    let list: Vec<i32> = vec![1, 2, 3];
+    let only_borrows = || list.len();
+    assert_eq!(list.len(), only_borrows());
+
+
+    // mutable borrow: no other borrow is allowed between the definition of
+    // the closure and its last call.
+    let mut list: Vec<i32> = vec![1, 2, 3];
+    let mut borrows_mutably = || list.push(7);
+    borrows_mutably();
+    borrows_mutably();
+    assert_eq!(list, [1, 2, 3, 7, 7]);
+
+
+    // by value: the body moves 'name' out, so the closure takes ownership.
+    let name: String = String::from("bob");
+    let consume = || name;
+    let owned: String = consume();
+    assert_eq!(owned, "bob");
+
+
+    // 'move' takes ownership even though the body only reads the value.
+    let list: Vec<i32> = vec![1, 2, 3];
+    let len = move || list.len();
+    assert_eq!(len(), 3); // 'list' itself is gone
+}
+
+
+// These only accept closures implementing the named trait.
+fn call_fn<F: Fn() -> usize>(f: F) -> usize {
+    f() + f()
+}
+
+fn call_fn_mut<F: FnMut()>(mut f: F) {
+    f();
+    f();
+}
+
+fn call_fn_once<F: FnOnce() -> String>(f: F) -> String {
+    f()
+}
+
+
+/// The compiler picks the traits a closure implements from its body:
+///  * 'FnOnce' for all closures: they can be called at least once;
+///  * 'FnMut' if it does not move captured values out, so it can be called
+///    more than once, possibly mutating what it captured;
+///  * 'Fn' if it neither moves out nor mutates, so it can be called more
+///    than once, even concurrently.
+///
+/// Every 'Fn' is also 'FnMut', and every 'FnMut' is also 'FnOnce'.
+///
+/// ```compile_fail,E0525
+/// fn call_twice<F: Fn() -> String>(f: F) { f(); f(); }
+///
+/// let name: String = String::from("bob");
+/// call_twice(move || name); // moves 'name' out: only FnOnce
+/// ```
+pub fn fn_traits() {
+
+    // Fn: only reads its captures, so it can go anywhere.
+    let list: Vec<i32> = vec![1, 2, 3];
+    assert_eq!(call_fn(|| list.len()), 6);
+    call_fn_mut(|| assert_eq!(list.len(), 3));
+    assert_eq!(call_fn_once(|| list.len().to_string()), "3");
+
+
+    // FnMut: mutates its captures, so it cannot be passed as Fn
+    // (see compile_fail/ch13_closures/mutate_in_fn.rs).
+    let mut count: i32 = 0;
+    call_fn_mut(|| count += 1);
+    assert_eq!(count, 2);
+
+    // 'sort_by_key' calls its closure several times, so it takes FnMut.
+    let mut rects: Vec<(u32, u32)> = vec![(10, 1), (3, 5), (7, 12)];
+    let mut num_sort_operations: usize = 0;
+    rects.sort_by_key(|r| {
+        num_sort_operations += 1;
+        r.0
+    });
+    assert_eq!(rects, [(3, 5), (7, 12), (10, 1)]);
+    assert!(num_sort_operations > 0);
+
+
+    // FnOnce: moves a capture out, so it can only be called once
+    // (see compile_fail/ch13_closures/move_out_of_fn_mut.rs).
+    let name: String = String::from("bob");
+    assert_eq!(call_fn_once(move || name), "bob");
+
+    // 'unwrap_or_else' calls its closure at most once: it takes FnOnce.
+    fn name_or(preference: Option<String>, fallback: String) -> String {
+        preference.unwrap_or_else(|| {
+            let mut name: String = fallback; // moved out of the closure
+            name.push('!');
+            name
+        })
+    }
+    assert_eq!(name_or(None, String::from("alice")), "alice!");
+    assert_eq!(name_or(Some(String::from("bob")), String::from("alice")), "bob");
+}
+
+
+/// A thread may outlive the function spawning it, so a closure given to
+/// 'thread::spawn' must own what it captures: it needs 'move'.
+///
+/// ```compile_fail,E0373
+/// use std::thread;
+///
+/// let list: Vec<i32> = vec![1, 2, 3];
+/// thread::spawn(|| println!("{list:?}")).join().unwrap(); // borrows 'list'
+/// ```
+pub fn move_into_thread() {
+
+    let list: Vec<i32> = vec![1, 2, 3];
+    let handle = thread::spawn(move || list.iter().sum::<i32>());
+    assert_eq!(handle.join().unwrap(), 6);
+
+    // 'list' was moved into the thread, so it cannot be used here anymore
+    // (see compile_fail/ch13_closures/thread_borrow.rs).
+}
+
+
+/// Returns a closure adding `n`; 'impl Fn' names the traits of its single,
+/// unnameable type.
+///
+/// ```
+/// use syntax_examples::ch13_closures::make_adder;
+///
+/// let add_two = make_adder(2);
+/// assert_eq!(add_two(3), 5);
+/// ```
+pub fn make_adder(n: i32) -> impl Fn(i32) -> i32 {
+    move |x| x + n // 'move': 'n' must outlive this call
+}
+
+
+/// Returns one of several closures; they all have different types, so they
+/// must be boxed behind a `Box<dyn Fn>` trait object.
+pub fn make_operation(name: &str) -> Box<dyn Fn(i32) -> i32> {
+    match name {
+        "double" => Box::new(|x| x * 2),
+        "negate" => Box::new(|x| -x),
+        _ => Box::new(|x| x),
+    }
+}
+
+
+/// Closures can be returned from functions and stored in collections, either
+/// behind 'impl Fn' (one concrete type) or `Box<dyn Fn>` (any of them).
+pub fn returning_closures() {
+
+    let add_two = make_adder(2);
+    assert_eq!(add_two(3), 5);
+
+    let operations: Vec<Box<dyn Fn(i32) -> i32>> = vec![
+        make_operation("double"),
+        make_operation("negate"),
+        Box::new(make_adder(10)),
+    ];
+    let result: i32 = operations.iter().fold(1, |acc, op| op(acc));
+    assert_eq!(result, 8); // -(1 * 2) + 10
+
+    // plain functions implement the Fn traits too.
+    fn square(x: i32) -> i32 {
+        x * x
+    }
+    let op: Box<dyn Fn(i32) -> i32> = Box::new(square);
+    assert_eq!(op(4), 16);
+}
+
+
+use crate::registry::{example, Difficulty, Example};
+
+pub const EXAMPLES: &[Example] = &[
+    example!(13, closure_syntax)
+        .summary("Closure syntax, and parameter types inferred from the first use.")
+        .tags(&["closures", "functions", "types"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch3_control_flow::function_call", "ch6_enum::enum_syntax"])
+        .shows(&["ShirtColor", "Inventory"])
+        .book("ch13-01-closures.html"),
+    example!(13, capture_modes)
+        .summary("Capturing by reference, by mutable reference, and by value with 'move'.")
+        .tags(&["closures", "borrowing", "move"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch13_closures::closure_syntax", "ch4_ownership::mutable_references"])
+        .book("ch13-01-closures.html#capturing-references-or-moving-ownership"),
+    example!(13, fn_traits)
+        .summary("Which of Fn, FnMut and FnOnce the compiler infers for a closure.")
+        .tags(&["closures", "traits", "generics"])
+        .difficulty(Difficulty::Advanced)
+        .requires(&["ch13_closures::capture_modes", "ch10_generics::trait_for_generic"])
+        .shows(&["call_fn", "call_fn_mut", "call_fn_once"])
+        .book("ch13-01-closures.html#moving-captured-values-out-of-closures-and-the-fn-traits"),
+    example!(13, move_into_thread)
+        .summary("'move' closures handed to 'thread::spawn'.")
+        .tags(&["closures", "move", "concurrency"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch13_closures::capture_modes"])
+        .book("ch13-01-closures.html#capturing-references-or-moving-ownership"),
+    example!(13, returning_closures)
+        .summary("Returning closures as 'impl Fn' and 'Box<dyn Fn>'.")
+        .tags(&["closures", "traits", "functions"])
+        .difficulty(Difficulty::Advanced)
+        .requires(&["ch13_closures::fn_traits", "ch10_generics::trait_for_generic"])
+        .shows(&["make_adder", "make_operation"])
+        .book("ch20-04-advanced-functions-and-closures.html#returning-closures"),
+];
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn giveaway_ties_go_to_blue() {
+        let store = Inventory { shirts: vec![ShirtColor::Red, ShirtColor::Blue] };
+        assert_eq!(store.giveaway(None), ShirtColor::Blue);
+
+        let empty = Inventory { shirts: vec![] };
+        assert_eq!(empty.giveaway(None), ShirtColor::Blue);
+        assert_eq!(empty.giveaway(Some(ShirtColor::Red)), ShirtColor::Red);
+    }
+
+    #[test]
+    fn helpers_call_their_closure() {
+        assert_eq!(call_fn(|| 21), 42); // twice
+        let mut calls: usize = 0;
+        call_fn_mut(|| calls += 1);
+        assert_eq!(calls, 2);
+        let name: String = String::from("bob");
+        assert_eq!(call_fn_once(move || name), "bob");
+    }
+
+    #[test]
+    fn adders_capture_their_own_value() {
+        let add_one = make_adder(1);
+        let add_ten = make_adder(10);
+        assert_eq!(add_ten(add_one(0)), 11);
+    }
+
+    #[test]
+    fn unknown_operation_is_identity() {
+        assert_eq!(make_operation("double")(21), 42);
+        assert_eq!(make_operation("negate")(7), -7);
+        assert_eq!(make_operation("unknown")(7), 7);
+    }
+}
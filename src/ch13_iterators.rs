@@ -0,0 +1,336 @@
+//! Chapter 13: processing a series of items with iterators.
+//!
+//! See <https://doc.rust-lang.org/book/ch13-02-iterators.html>.
+
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+
+
+/// Counts from 1 to 5.
+///
+/// ```
+/// use syntax_examples::ch13_iterators::Counter;
+///
+/// let doubled: Vec<u32> = Counter::new().map(|x| x * 2).collect();
+/// assert_eq!(doubled, [2, 4, 6, 8, 10]);
+/// ```
+pub struct Counter {
+    count: u32,
+}
+
+
+impl Counter {
+    pub fn new() -> Counter {
+        Counter { count: 0 }
+    }
+}
+
+
+impl Default for Counter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+// 'next' is the only method to write; every other one, such as 'map' or
+// 'sum', has a default implementation built on it.
+impl Iterator for Counter {
+    type Item = u32; // the associated type of the items
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.count < 5 {
+            self.count += 1;
+            Some(self.count)
+        } else {
+            None
+        }
+    }
+}
+
+
+/// An iterator produces items through 'next', which returns 'Some(item)'
+/// until the items run out, then 'None'. 'for' loops call 'next' for us.
+///
+/// ```
+/// let v1: Vec<i32> = vec![1, 2, 3];
+/// let mut v1_iter = v1.iter(); // 'next' changes its state: it must be 'mut'
+///
+/// assert_eq!(v1_iter.next(), Some(&1));
+/// assert_eq!(v1_iter.next(), Some(&2));
+/// assert_eq!(v1_iter.next(), Some(&3));
+/// assert_eq!(v1_iter.next(), None);
+/// ```
+pub fn iterator_trait() {
+
+    let mut counter: Counter = Counter::new();
+    assert_eq!(counter.next(), Some(1));
+    assert_eq!(counter.next(), Some(2));
+
+    // a 'for' loop takes the rest.
+    let mut rest: Vec<u32> = Vec::new();
+    for n in counter {
+        rest.push(n);
+    }
+    assert_eq!(rest, [3, 4, 5]);
+
+
+    // 'iter' yields '&T', 'iter_mut' yields '&mut T', and 'into_iter' takes
+    // ownership of the collection and yields 'T'.
+    let mut names: Vec<String> = vec![String::from("bob"), String::from("alice")];
+    let lengths: Vec<usize> = names.iter().map(|name| name.len()).collect();
+    assert_eq!(lengths, [3, 5]);
+
+    for name in names.iter_mut() {
+        name.push('!');
+    }
+
+    let owned: Vec<String> = names.into_iter().collect(); // 'names' is moved
+    assert_eq!(owned, ["bob!", "alice!"]);
+}
+
+
+/// Consuming adapters call 'next' until the end and use up the iterator:
+/// 'sum', 'fold', 'count', 'collect'... 'collect' builds whatever collection
+/// its type annotation asks for.
+///
+/// ```
+/// let v1: Vec<i32> = vec![1, 2, 3];
+/// let total: i32 = v1.iter().sum();
+/// assert_eq!(total, 6);
+/// ```
+pub fn consuming_adapters() {
+
+    assert_eq!(Counter::new().sum::<u32>(), 15);
+    assert_eq!(Counter::new().count(), 5);
+    assert_eq!(Counter::new().fold(0, |acc, x| acc * 10 + x), 12345);
+
+
+    // one iterator, several collections.
+    let words: Vec<&str> = vec!["apple", "bob", "apple"];
+    let vector: Vec<&str> = words.iter().map(|w| &w[..1]).collect();
+    let set: HashSet<&str> = words.iter().copied().collect();
+    let lengths: HashMap<&str, usize> = words.iter().map(|w| (*w, w.len())).collect();
+    let sentence: String = words.iter().map(|w| w.to_uppercase()).collect();
+    assert_eq!(vector, ["a", "b", "a"]);
+    assert_eq!(set.len(), 2);
+    assert_eq!(lengths["bob"], 3);
+    assert_eq!(sentence, "APPLEBOBAPPLE");
+
+
+    // collecting 'Result's gives 'Ok' of all the values, or the first 'Err'.
+    let parsed: Result<Vec<i32>, _> = ["1", "2", "3"].iter().map(|s| s.parse::<i32>()).collect();
+    assert_eq!(parsed, Ok(vec![1, 2, 3]));
+    let parsed: Result<Vec<i32>, _> = ["1", "two", "3"].iter().map(|s| s.parse::<i32>()).collect();
+    assert!(parsed.is_err());
+}
+
+
+/// Iterator adapters turn an iterator into another one: 'map', 'filter',
+/// 'zip', 'skip', 'take_while', 'enumerate', 'rev'... Chains of them read
+/// like a description of the result.
+pub fn iterator_adapters() {
+
+    let v1: Vec<i32> = vec![1, 2, 3];
+    let v2: Vec<i32> = v1.iter().map(|x| x + 1).collect();
+    assert_eq!(v2, [2, 3, 4]);
+
+    let evens: Vec<u32> = Counter::new().filter(|x| x % 2 == 0).collect();
+    assert_eq!(evens, [2, 4]);
+
+    let pairs: Vec<(u32, char)> = Counter::new().zip("abc".chars()).collect();
+    assert_eq!(pairs, [(1, 'a'), (2, 'b'), (3, 'c')]); // stops at the shorter
+
+    let small: Vec<u32> = Counter::new().take_while(|&x| x < 3).collect();
+    assert_eq!(small, [1, 2]);
+
+    let indexed: Vec<(usize, u32)> = Counter::new().skip(3).enumerate().collect();
+    assert_eq!(indexed, [(0, 4), (1, 5)]);
+
+
+    // pair 1..=5 with 2..=5, multiply, keep the multiples of 3, and sum.
+    let sum: u32 = Counter::new()
+        .zip(Counter::new().skip(1))
+        .map(|(a, b)| a * b)
+        .filter(|x| x % 3 == 0)
+        .sum();
+    assert_eq!(sum, 18); // 2*3 + 3*4
+}
+
+
+/// Adapters are lazy: they do nothing until a consuming adapter pulls items
+/// through them, and then only as many as it needs.
+///
+/// ```
+/// let v1: Vec<i32> = vec![1, 2, 3];
+/// v1.iter().map(|x| x + 1); // warning: iterators are lazy and do nothing unless consumed
+/// ```
+pub fn laziness() {
+
+    // 'Cell' lets the closure count its calls while we can still read it.
+    let calls: Cell<u32> = Cell::new(0);
+    let doubled = Counter::new().map(|x| {
+        calls.set(calls.get() + 1);
+        x * 2
+    });
+    assert_eq!(calls.get(), 0); // nothing ran yet
+
+    let first_two: Vec<u32> = doubled.take(2).collect();
+    assert_eq!(first_two, [2, 4]);
+    assert_eq!(calls.get(), 2); // only the items taken
+
+
+    // each item goes through the whole chain before the next one starts.
+    let log: RefCell<Vec<String>> = RefCell::new(Vec::new());
+    let found: Option<u32> = Counter::new()
+        .map(|x| {
+            log.borrow_mut().push(format!("map {x}"));
+            x * 10
+        })
+        .find(|&x| {
+            log.borrow_mut().push(format!("find {x}"));
+            x > 15
+        });
+    assert_eq!(found, Some(20));
+    assert_eq!(log.into_inner(), ["map 1", "find 10", "map 2", "find 20"]);
+}
+
+
+#[derive(PartialEq, Debug)]
+pub struct Shoe {
+    pub size: u32,
+    pub style: String,
+}
+
+
+/// The shoes of the given size; the closure passed to 'filter' captures
+/// `shoe_size` from the function's environment.
+///
+/// ```
+/// use syntax_examples::ch13_iterators::{shoes_in_size, Shoe};
+///
+/// let shoes = vec![
+///     Shoe { size: 10, style: String::from("sneaker") },
+///     Shoe { size: 13, style: String::from("sandal") },
+/// ];
+/// assert_eq!(shoes_in_size(shoes, 10).len(), 1);
+/// ```
+pub fn shoes_in_size(shoes: Vec<Shoe>, shoe_size: u32) -> Vec<Shoe> {
+    shoes.into_iter().filter(|s| s.size == shoe_size).collect()
+}
+
+
+/// Closures given to adapters can capture their environment.
+pub fn filter_by_size() {
+
+    let shoes: Vec<Shoe> = vec![
+        Shoe { size: 10, style: String::from("sneaker") },
+        Shoe { size: 13, style: String::from("sandal") },
+        Shoe { size: 10, style: String::from("boot") },
+    ];
+
+    let in_my_size: Vec<Shoe> = shoes_in_size(shoes, 10);
+    let styles: Vec<&str> = in_my_size.iter().map(|s| s.style.as_str()).collect();
+    assert_eq!(styles, ["sneaker", "boot"]);
+}
+
+
+use crate::registry::{example, Difficulty, Example};
+
+pub const EXAMPLES: &[Example] = &[
+    example!(13, iterator_trait)
+        .summary("The Iterator trait, 'next', and a custom Counter iterator.")
+        .tags(&["iterators", "traits", "loops"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch3_control_flow::for_and_while", "ch10_generics::trait_for_generic"])
+        .shows(&["Counter"])
+        .book("ch13-02-iterators.html#the-iterator-trait-and-the-next-method"),
+    example!(13, consuming_adapters)
+        .summary("'sum', 'fold', 'count', and 'collect' into different collections.")
+        .tags(&["iterators", "collections"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch13_iterators::iterator_trait", "ch13_closures::closure_syntax", "ch8_collections::hashmap_syntax"])
+        .shows(&["Counter"])
+        .book("ch13-02-iterators.html#methods-that-consume-the-iterator"),
+    example!(13, iterator_adapters)
+        .summary("'map', 'filter', 'zip', 'skip', 'take_while' and chains of them.")
+        .tags(&["iterators", "closures"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch13_iterators::consuming_adapters"])
+        .shows(&["Counter"])
+        .book("ch13-02-iterators.html#methods-that-produce-other-iterators"),
+    example!(13, laziness)
+        .summary("Adapters do nothing until consumed, one item at a time.")
+        .tags(&["iterators", "closures"])
+        .difficulty(Difficulty::Advanced)
+        .requires(&["ch13_iterators::iterator_adapters"])
+        .shows(&["Counter"])
+        .book("ch13-02-iterators.html#methods-that-produce-other-iterators"),
+    example!(13, filter_by_size)
+        .summary("'filter' with a closure capturing its environment: 'shoes_in_size'.")
+        .tags(&["iterators", "closures", "structs"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch13_iterators::iterator_adapters", "ch13_closures::capture_modes"])
+        .shows(&["Shoe", "shoes_in_size"])
+        .book("ch13-02-iterators.html#using-closures-that-capture-their-environment"),
+];
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counter_stays_exhausted() {
+        let mut counter: Counter = Counter::new();
+        assert_eq!(counter.by_ref().count(), 5);
+        assert_eq!(counter.next(), None);
+        assert_eq!(counter.next(), None);
+    }
+
+    #[test]
+    fn filters_by_size() {
+        let shoes = vec![
+            Shoe { size: 10, style: String::from("sneaker") },
+            Shoe { size: 13, style: String::from("sandal") },
+            Shoe { size: 10, style: String::from("boot") },
+        ];
+
+        let in_my_size = shoes_in_size(shoes, 10);
+
+        assert_eq!(
+            in_my_size,
+            vec![
+                Shoe { size: 10, style: String::from("sneaker") },
+                Shoe { size: 10, style: String::from("boot") },
+            ]
+        );
+    }
+
+    #[test]
+    fn no_shoes_in_size() {
+        let shoes = vec![Shoe { size: 13, style: String::from("sandal") }];
+        assert!(shoes_in_size(shoes, 10).is_empty());
+        assert!(shoes_in_size(Vec::new(), 10).is_empty());
+    }
+
+    // an exhausted 'Counter' behaves as any empty iterator.
+    #[test]
+    fn empty_iterators() {
+        let exhausted = || {
+            let mut counter: Counter = Counter::new();
+            counter.by_ref().for_each(drop);
+            counter
+        };
+        assert_eq!(exhausted().sum::<u32>(), 0);
+        assert_eq!(exhausted().fold(7, |acc, x| acc + x), 7);
+        assert_eq!(exhausted().max(), None);
+        assert_eq!(Counter::new().zip(exhausted()).count(), 0);
+    }
+
+    #[test]
+    fn take_while_stops_at_first_failure() {
+        let taken: Vec<u32> = Counter::new().take_while(|x| x % 2 == 1).collect();
+        assert_eq!(taken, [1]); // unlike 'filter', 3 and 5 are never reached
+    }
+}
@@ -0,0 +1,440 @@
+//! Chapter 15: smart pointers, 'Box', 'Rc', 'RefCell' and 'Weak', and the
+//! 'Deref' and 'Drop' traits that make them smart.
+//!
+//! See <https://doc.rust-lang.org/book/ch15-00-smart-pointers.html>.
+
+use std::cell::RefCell;
+use std::ops::Deref;
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::{Rc, Weak};
+
+
+/// A cons list: each item holds a value and the rest of the list. The rest
+/// is boxed, or the type would contain itself and have an infinite size.
+///
+/// ```compile_fail,E0072
+/// enum List {
+///     Cons(i32, List),
+///     Nil,
+/// }
+/// ```
+#[derive(Debug)]
+pub enum List {
+    Cons(i32, Box<List>),
+    Nil,
+}
+
+
+impl List {
+    pub fn sum(&self) -> i32 {
+        match self {
+            List::Cons(value, rest) => value + rest.sum(),
+            List::Nil => 0,
+        }
+    }
+}
+
+
+/// `Box<T>` puts its value on the heap and owns it; the box itself is a
+/// pointer of known size. It is dropped, with its value, when it goes out of
+/// scope.
+pub fn box_cons_list() {
+
+    let b: Box<i32> = Box::new(5);
+    assert_eq!(*b, 5);
+
+
+    // 'Cons(1, Cons(2, Cons(3, Nil)))', each 'Cons' in its own box.
+    use List::{Cons, Nil};
+    let list: List = Cons(1, Box::new(Cons(2, Box::new(Cons(3, Box::new(Nil))))));
+    assert_eq!(list.sum(), 6);
+}
+
+
+/// A tuple struct holding one value, that derefs to it like a 'Box' does,
+/// though it keeps the value on the stack.
+///
+/// ```compile_fail,E0614
+/// struct MyBox<T>(T);
+///
+/// let y = MyBox(5);
+/// assert_eq!(5, *y); // MyBox does not implement Deref
+/// ```
+pub struct MyBox<T>(T);
+
+
+impl<T> MyBox<T> {
+    pub fn new(x: T) -> MyBox<T> {
+        MyBox(x)
+    }
+}
+
+
+impl<T> Deref for MyBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+
+/// Implementing 'Deref' makes '*' work on a type: '*y' means '*(y.deref())'.
+/// It also enables deref coercion: a `&MyBox<String>` passed where a '&str'
+/// is expected is turned into one through 'deref' calls, at compile time.
+///
+/// ```
+/// use syntax_examples::ch15_smart_pointers::MyBox;
+///
+/// fn hello(name: &str) -> String {
+///     format!("Hello, {name}!")
+/// }
+///
+/// let m = MyBox::new(String::from("Rust"));
+/// assert_eq!(hello(&m), "Hello, Rust!"); // &MyBox<String> -> &String -> &str
+/// ```
+pub fn deref_trait() {
+
+    let x: i32 = 5;
+    let y: MyBox<i32> = MyBox::new(x);
+    assert_eq!(5, *y);
+
+
+    fn hello(name: &str) -> String {
+        format!("Hello, {name}!")
+    }
+
+    let m: MyBox<String> = MyBox::new(String::from("Rust"));
+    assert_eq!(hello(&m), "Hello, Rust!");
+    assert_eq!(hello(&(*m)[..]), "Hello, Rust!"); // without deref coercion
+
+    // methods are found through deref too.
+    assert_eq!(m.len(), 4);
+}
+
+
+/// Prints, and records in `log`, when it is dropped.
+pub struct CustomSmartPointer<'a> {
+    pub data: String,
+    pub log: &'a RefCell<Vec<String>>,
+}
+
+
+impl Drop for CustomSmartPointer<'_> {
+    fn drop(&mut self) {
+        let message: String = format!("Dropping CustomSmartPointer with data `{}`!", self.data);
+        println!("{message}");
+        self.log.borrow_mut().push(message);
+    }
+}
+
+
+/// 'Drop::drop' runs when a value goes out of scope: local variables are
+/// dropped in the reverse order of their creation. 'drop' cannot be called
+/// directly; 'std::mem::drop' drops a value early instead.
+///
+/// ```compile_fail,E0040
+/// struct Noisy;
+///
+/// impl Drop for Noisy {
+///     fn drop(&mut self) {}
+/// }
+///
+/// let n = Noisy;
+/// n.drop(); // explicit destructor calls are not allowed
+/// ```
+pub fn drop_order() {
+
+    let log: RefCell<Vec<String>> = RefCell::new(Vec::new());
+    {
+        let _c = CustomSmartPointer { data: String::from("my stuff"), log: &log };
+        let _d = CustomSmartPointer { data: String::from("other stuff"), log: &log };
+        println!("CustomSmartPointers created.");
+    }
+    assert_eq!(log.borrow().as_slice(), [
+        "Dropping CustomSmartPointer with data `other stuff`!",
+        "Dropping CustomSmartPointer with data `my stuff`!",
+    ]);
+
+
+    log.borrow_mut().clear();
+    let c = CustomSmartPointer { data: String::from("some data"), log: &log };
+    drop(c); // 'std::mem::drop', in the prelude
+    println!("CustomSmartPointer dropped before the end of the scope.");
+    assert_eq!(log.borrow().len(), 1);
+}
+
+
+/// A cons list whose tails can be shared.
+#[derive(Debug)]
+pub enum RcList {
+    Cons(i32, Rc<RcList>),
+    Nil,
+}
+
+
+/// `Rc<T>` counts the owners of its value, and drops the value with the last
+/// one. 'Rc::clone' only increments the count; it does not copy the value.
+/// 'Rc' is for single-threaded code, and only gives immutable access.
+///
+/// ```compile_fail,E0596
+/// use std::rc::Rc;
+///
+/// let shared: Rc<Vec<i32>> = Rc::new(vec![1, 2, 3]);
+/// shared.push(4); // cannot borrow data in an 'Rc' as mutable
+/// ```
+pub fn rc_shared() {
+
+    use RcList::{Cons, Nil};
+
+    let a: Rc<RcList> = Rc::new(Cons(5, Rc::new(Cons(10, Rc::new(Nil)))));
+    assert_eq!(Rc::strong_count(&a), 1);
+
+    // 'b' and 'c' both share 'a' as their tail.
+    let _b: RcList = Cons(3, Rc::clone(&a));
+    assert_eq!(Rc::strong_count(&a), 2);
+    {
+        let _c: RcList = Cons(4, Rc::clone(&a));
+        assert_eq!(Rc::strong_count(&a), 3);
+    }
+    assert_eq!(Rc::strong_count(&a), 2); // '_c' was dropped
+}
+
+
+/// `RefCell<T>` moves the borrowing rules to run time: 'borrow' and
+/// 'borrow_mut' return guards, and any number of 'Ref's or a single
+/// 'RefMut' may be alive at once. Breaking the rule panics instead of
+/// failing to compile. This lets a value be mutated through a '&' reference
+/// ("interior mutability").
+///
+/// ```should_panic
+/// use std::cell::RefCell;
+///
+/// let cell: RefCell<Vec<i32>> = RefCell::new(vec![]);
+/// let _one = cell.borrow_mut();
+/// let _two = cell.borrow_mut(); // panics: already borrowed
+/// ```
+pub fn refcell_borrows() {
+
+    // interior mutability: 'send' takes '&self' but still records messages.
+    struct MockMessenger {
+        sent_messages: RefCell<Vec<String>>,
+    }
+
+    impl MockMessenger {
+        fn send(&self, message: &str) {
+            self.sent_messages.borrow_mut().push(String::from(message));
+        }
+    }
+
+    let messenger = MockMessenger { sent_messages: RefCell::new(vec![]) };
+    messenger.send("Warning: You've used up over 75% of your quota!");
+    assert_eq!(messenger.sent_messages.borrow().len(), 1);
+
+
+    // many immutable borrows at once are fine...
+    let cell: RefCell<i32> = RefCell::new(5);
+    {
+        let r1 = cell.borrow();
+        let r2 = cell.borrow();
+        assert_eq!(*r1 + *r2, 10);
+        assert!(cell.try_borrow_mut().is_err()); // ...but not with a mutable one
+    }
+    *cell.borrow_mut() += 1; // the guards above are gone
+    assert_eq!(*cell.borrow(), 6);
+
+
+    // two mutable borrows at once: 'borrow_mut' panics at run time.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let _one = cell.borrow_mut();
+        let _two = cell.borrow_mut();
+    }));
+    let payload = result.unwrap_err();
+    let message: &str = payload.downcast_ref::<&str>().copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap();
+    assert!(message.contains("already borrowed"), "panicked with `{message}`");
+    assert_eq!(*cell.borrow(), 6); // the guards were released while unwinding
+}
+
+
+/// `Rc<RefCell<T>>` combines both: several owners, each able to mutate.
+pub fn rc_refcell() {
+
+    let value: Rc<RefCell<i32>> = Rc::new(RefCell::new(5));
+    let a: Rc<RefCell<i32>> = Rc::clone(&value);
+    let b: Rc<RefCell<i32>> = Rc::clone(&value);
+
+    *a.borrow_mut() += 10;
+    *b.borrow_mut() *= 2;
+    assert_eq!(*value.borrow(), 30);
+    assert_eq!(Rc::strong_count(&value), 3);
+}
+
+
+/// A tree node: it owns its children, and only points weakly to its parent.
+#[derive(Debug)]
+pub struct Node {
+    pub value: i32,
+    pub parent: RefCell<Weak<Node>>,
+    pub children: RefCell<Vec<Rc<Node>>>,
+}
+
+
+/// 'Rc' values that point to each other in a cycle never reach a count of 0
+/// and leak. `Weak<T>`, made by 'Rc::downgrade', does not own its value: it
+/// is counted apart ('weak_count'), and 'upgrade' returns 'None' once the
+/// value is gone. Parents own children with 'Rc', and children point back
+/// with 'Weak', so dropping the tree frees every node.
+pub fn weak_tree() {
+
+    let leaf: Rc<Node> = Rc::new(Node {
+        value: 3,
+        parent: RefCell::new(Weak::new()),
+        children: RefCell::new(vec![]),
+    });
+    assert!(leaf.parent.borrow().upgrade().is_none());
+
+    let weak_branch: Weak<Node>;
+    {
+        let branch: Rc<Node> = Rc::new(Node {
+            value: 5,
+            parent: RefCell::new(Weak::new()),
+            children: RefCell::new(vec![Rc::clone(&leaf)]),
+        });
+        *leaf.parent.borrow_mut() = Rc::downgrade(&branch);
+        weak_branch = Rc::downgrade(&branch);
+
+        assert_eq!(leaf.parent.borrow().upgrade().map(|p| p.value), Some(5));
+        assert_eq!((Rc::strong_count(&branch), Rc::weak_count(&branch)), (1, 2));
+        assert_eq!((Rc::strong_count(&leaf), Rc::weak_count(&leaf)), (2, 0));
+    }
+
+    // the branch was freed despite its child pointing to it: no leak.
+    assert!(weak_branch.upgrade().is_none());
+    assert!(leaf.parent.borrow().upgrade().is_none());
+    assert_eq!(Rc::strong_count(&leaf), 1);
+}
+
+
+use crate::registry::{example, Difficulty, Example};
+
+pub const EXAMPLES: &[Example] = &[
+    example!(15, box_cons_list)
+        .summary("'Box<T>' on the heap, and a recursive cons list.")
+        .tags(&["smart-pointers", "enums"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch6_enum::match_syntax", "ch4_ownership::shallow_transfer"])
+        .shows(&["List"])
+        .book("ch15-01-box.html"),
+    example!(15, deref_trait)
+        .summary("A custom 'MyBox<T>' with 'Deref', and deref coercion.")
+        .tags(&["smart-pointers", "traits", "references"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch15_smart_pointers::box_cons_list", "ch10_generics::trait_for_generic"])
+        .shows(&["MyBox"])
+        .book("ch15-02-deref.html"),
+    example!(15, drop_order)
+        .summary("'Drop' runs in reverse order of creation; 'drop' drops early.")
+        .tags(&["smart-pointers", "traits", "scope"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch15_smart_pointers::box_cons_list", "ch1_basics::scope"])
+        .shows(&["CustomSmartPointer"])
+        .book("ch15-03-drop.html"),
+    example!(15, rc_shared)
+        .summary("Shared ownership with 'Rc<T>' and 'Rc::strong_count'.")
+        .tags(&["smart-pointers", "ownership"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch15_smart_pointers::box_cons_list"])
+        .shows(&["RcList"])
+        .book("ch15-04-rc.html"),
+    example!(15, refcell_borrows)
+        .summary("'RefCell<T>' checks borrows at run time, and panics on a double borrow.")
+        .tags(&["smart-pointers", "borrowing", "mutability", "panic"])
+        .difficulty(Difficulty::Advanced)
+        .requires(&["ch15_smart_pointers::rc_shared", "ch4_ownership::switch_between_references"])
+        .book("ch15-05-interior-mutability.html"),
+    example!(15, rc_refcell)
+        .summary("Several owners of mutable data with 'Rc<RefCell<T>>'.")
+        .tags(&["smart-pointers", "mutability"])
+        .difficulty(Difficulty::Advanced)
+        .requires(&["ch15_smart_pointers::refcell_borrows"])
+        .book("ch15-05-interior-mutability.html#allowing-multiple-owners-of-mutable-data-with-rct-and-refcellt"),
+    example!(15, weak_tree)
+        .summary("A tree with 'Weak' parent pointers that does not leak.")
+        .tags(&["smart-pointers", "ownership"])
+        .difficulty(Difficulty::Advanced)
+        .requires(&["ch15_smart_pointers::rc_refcell"])
+        .shows(&["Node"])
+        .book("ch15-06-reference-cycles.html"),
+];
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_list_sums_to_zero() {
+        assert_eq!(List::Nil.sum(), 0);
+        let one: List = List::Cons(-1, Box::new(List::Nil));
+        assert_eq!(one.sum(), -1);
+    }
+
+    #[test]
+    fn struct_fields_drop_after_the_struct() {
+        let log: RefCell<Vec<String>> = RefCell::new(Vec::new());
+        {
+            let _pair = (
+                CustomSmartPointer { data: String::from("first"), log: &log },
+                CustomSmartPointer { data: String::from("second"), log: &log },
+            );
+        }
+        // unlike variables, the fields of a tuple or struct drop in order.
+        assert_eq!(log.borrow().as_slice(), [
+            "Dropping CustomSmartPointer with data `first`!",
+            "Dropping CustomSmartPointer with data `second`!",
+        ]);
+    }
+
+    #[test]
+    fn moved_value_drops_once() {
+        let log: RefCell<Vec<String>> = RefCell::new(Vec::new());
+        let c = CustomSmartPointer { data: String::from("moved"), log: &log };
+        let boxed: Box<CustomSmartPointer> = Box::new(c);
+        assert!(log.borrow().is_empty());
+        drop(boxed);
+        assert_eq!(log.borrow().len(), 1);
+    }
+
+    #[test]
+    fn my_box_derefs_to_its_value() {
+        assert_eq!(*MyBox::new(5), 5);
+        let name: MyBox<String> = MyBox::new(String::from("Rust"));
+        assert_eq!(name.len(), 4); // '&MyBox<String>' to '&String' to '&str'
+    }
+
+    #[test]
+    fn cycle_can_be_broken_by_hand() {
+        // two nodes owning each other with 'Rc' keep each other alive...
+        let a: Rc<Node> = Rc::new(Node {
+            value: 1,
+            parent: RefCell::new(Weak::new()),
+            children: RefCell::new(vec![]),
+        });
+        let b: Rc<Node> = Rc::new(Node {
+            value: 2,
+            parent: RefCell::new(Weak::new()),
+            children: RefCell::new(vec![Rc::clone(&a)]),
+        });
+        a.children.borrow_mut().push(Rc::clone(&b));
+        assert_eq!((Rc::strong_count(&a), Rc::strong_count(&b)), (2, 2));
+
+        // ...until one of the links is removed.
+        let weak_a: Weak<Node> = Rc::downgrade(&a);
+        a.children.borrow_mut().clear();
+        drop(a);
+        drop(b);
+        assert!(weak_a.upgrade().is_none());
+    }
+}
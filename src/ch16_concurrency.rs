@@ -0,0 +1,269 @@
+//! Chapter 16: fearless concurrency with threads, channels and mutexes.
+//!
+//! Threads run in an order that changes from run to run, so the examples
+//! only assert what does not depend on it: joined results, totals, and sorted
+//! collections.
+//!
+//! See <https://doc.rust-lang.org/book/ch16-00-concurrency.html>.
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+
+
+/// 'thread::spawn' runs a closure in a new thread and returns a 'JoinHandle';
+/// 'join' waits for the thread to finish and returns what the closure
+/// returned, or 'Err' if the thread panicked. Without 'join', the spawned
+/// threads are stopped when 'main' returns.
+///
+/// ```
+/// use std::thread;
+///
+/// let handle = thread::spawn(|| 1 + 1);
+/// assert_eq!(handle.join().unwrap(), 2);
+/// ```
+pub fn spawn_and_join() {
+
+    let handle: JoinHandle<u32> = thread::spawn(|| (1..=10).sum());
+    assert_eq!(handle.join().unwrap(), 55);
+
+
+    // several threads at once; the results come back in the order of the
+    // handles, whatever order the threads ran in.
+    let handles: Vec<JoinHandle<u64>> = (1..=4)
+        .map(|n| thread::spawn(move || n * n))
+        .collect();
+    let squares: Vec<u64> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    assert_eq!(squares, [1, 4, 9, 16]);
+
+
+    // a 'Builder' configures the thread, eg. its name. 'join' returns 'Ok'
+    // since the thread did not panic; a panic would stay in the thread and
+    // come back as an 'Err'.
+    let handle: JoinHandle<()> = thread::Builder::new()
+        .name(String::from("worker"))
+        .spawn(|| {
+            assert_eq!(thread::current().name(), Some("worker"));
+        })
+        .unwrap();
+    assert!(handle.join().is_ok());
+}
+
+
+/// A spawned thread may outlive the variables around it, so its closure must
+/// own what it uses: 'move' transfers ownership into the thread
+/// (see compile_fail/ch13_closures/thread_borrow.rs).
+pub fn move_closures() {
+
+    let v: Vec<i32> = vec![1, 2, 3];
+    let handle: JoinHandle<i32> = thread::spawn(move || v.iter().sum());
+    // 'v' belongs to the thread now; 'drop(v)' here would not compile.
+    assert_eq!(handle.join().unwrap(), 6);
+
+
+    // to keep using a value, move a clone of it.
+    let names: Vec<String> = vec![String::from("bob"), String::from("alice")];
+    let copy: Vec<String> = names.clone();
+    let handle: JoinHandle<usize> = thread::spawn(move || copy.len());
+    assert_eq!(handle.join().unwrap(), names.len());
+}
+
+
+/// A channel sends values from any number of producers ('Sender', cloned
+/// for each) to one consumer ('Receiver'). 'send' moves the value into the
+/// channel; 'recv' blocks until a value arrives, or returns 'Err' once every
+/// sender is dropped, which also ends a 'for' loop over the receiver.
+///
+/// ```compile_fail,E0382
+/// use std::sync::mpsc;
+///
+/// let (tx, _rx) = mpsc::channel();
+/// let val = String::from("hi");
+/// tx.send(val).unwrap();
+/// println!("val is {val}"); // 'val' was moved into the channel
+/// ```
+pub fn message_passing() {
+
+    // the sending thread cannot use the value after 'send'
+    // (see compile_fail/ch16_concurrency/use_after_send.rs).
+    let (tx, rx): (Sender<String>, Receiver<String>) = mpsc::channel();
+    thread::spawn(move || {
+        tx.send(String::from("hi")).unwrap();
+    });
+    assert_eq!(rx.recv().unwrap(), "hi");
+    assert!(rx.recv().is_err()); // the only sender is gone
+
+
+    // multiple producers: one clone of the sender per thread.
+    let (tx, rx): (Sender<u32>, Receiver<u32>) = mpsc::channel();
+    for id in 0..3 {
+        let tx: Sender<u32> = tx.clone();
+        thread::spawn(move || {
+            for i in 0..3 {
+                tx.send(id * 10 + i).unwrap();
+            }
+        });
+    }
+    drop(tx); // or the loop below would wait for it forever
+
+    // values from different threads interleave in any order; sort them.
+    let mut received: Vec<u32> = rx.iter().collect();
+    received.sort();
+    assert_eq!(received, [0, 1, 2, 10, 11, 12, 20, 21, 22]);
+}
+
+
+/// `Mutex<T>` lets one thread at a time access its value: 'lock' blocks
+/// until the lock is free, and returns a guard that unlocks when dropped.
+/// To share the mutex itself between threads, wrap it in `Arc<T>`, an
+/// atomically reference counted 'Rc' that is safe to send across threads.
+///
+/// ```compile_fail,E0277
+/// use std::rc::Rc;
+/// use std::sync::Mutex;
+/// use std::thread;
+///
+/// let counter = Rc::new(Mutex::new(0));
+/// let counter2 = Rc::clone(&counter);
+/// thread::spawn(move || *counter2.lock().unwrap() += 1); // Rc is not Send
+/// ```
+pub fn shared_state() {
+
+    let m: Mutex<i32> = Mutex::new(5);
+    {
+        let mut num: MutexGuard<i32> = m.lock().unwrap();
+        *num = 6;
+    } // the guard is dropped here, unlocking the mutex
+    assert_eq!(*m.lock().unwrap(), 6);
+
+
+    // a counter shared by ten threads
+    // (see compile_fail/ch16_concurrency/rc_across_threads.rs for 'Rc').
+    let counter: Arc<Mutex<i32>> = Arc::new(Mutex::new(0));
+    let mut handles: Vec<JoinHandle<()>> = vec![];
+
+    for _ in 0..10 {
+        let counter: Arc<Mutex<i32>> = Arc::clone(&counter);
+        let handle: JoinHandle<()> = thread::spawn(move || {
+            let mut num: MutexGuard<i32> = counter.lock().unwrap();
+            *num += 1;
+        });
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(*counter.lock().unwrap(), 10);
+    assert_eq!(Arc::strong_count(&counter), 1); // the threads' clones are gone
+}
+
+
+/// 'thread::scope' joins every thread spawned in it before returning, so
+/// those threads may borrow local variables, without 'move' or 'Arc'. The
+/// borrow rules still apply: many threads can read the same data, or each
+/// can write its own part of it, but two cannot write the same variable
+/// (see compile_fail/ch16_concurrency/unsynchronized_mutation.rs).
+///
+/// ```
+/// use std::thread;
+///
+/// let numbers: Vec<i32> = vec![1, 2, 3];
+/// let total: i32 = thread::scope(|s| {
+///     s.spawn(|| numbers.iter().sum()).join().unwrap()
+/// });
+/// assert_eq!(total, 6);
+/// ```
+pub fn scoped_threads() {
+
+    let numbers: Vec<i32> = (1..=100).collect();
+
+    // shared reads: both threads borrow 'numbers'.
+    let (evens, odds): (i32, i32) = thread::scope(|s| {
+        let evens = s.spawn(|| numbers.iter().filter(|&&n| n % 2 == 0).sum());
+        let odds = s.spawn(|| numbers.iter().filter(|&&n| n % 2 == 1).sum());
+        (evens.join().unwrap(), odds.join().unwrap())
+    });
+    assert_eq!((evens, odds), (2550, 2500));
+
+
+    // disjoint writes: each thread gets its own '&mut' chunk.
+    let mut data: Vec<i32> = numbers.clone();
+    thread::scope(|s| {
+        for chunk in data.chunks_mut(25) {
+            s.spawn(move || {
+                for n in chunk {
+                    *n *= 2;
+                }
+            });
+        }
+    }); // every thread has finished here
+    assert_eq!(data.iter().sum::<i32>(), 2 * 5050);
+
+
+    // one variable written by several threads needs a lock; a 'RefCell' is
+    // rejected (see compile_fail/ch16_concurrency/refcell_across_threads.rs).
+    let total: Mutex<i32> = Mutex::new(0);
+    thread::scope(|s| {
+        for chunk in numbers.chunks(10) {
+            let total: &Mutex<i32> = &total;
+            s.spawn(move || *total.lock().unwrap() += chunk.iter().sum::<i32>());
+        }
+    });
+    assert_eq!(total.into_inner().unwrap(), 5050);
+}
+
+
+use crate::registry::{example, Difficulty, Example};
+
+pub const EXAMPLES: &[Example] = &[
+    example!(16, spawn_and_join)
+        .summary("'thread::spawn', and 'JoinHandle::join' for results and panics.")
+        .tags(&["concurrency", "closures"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch13_closures::move_into_thread"])
+        .book("ch16-01-threads.html"),
+    example!(16, move_closures)
+        .summary("'move' closures give threads ownership of their data.")
+        .tags(&["concurrency", "closures", "move"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch16_concurrency::spawn_and_join", "ch4_ownership::deep_copy"])
+        .book("ch16-01-threads.html#using-move-closures-with-threads"),
+    example!(16, message_passing)
+        .summary("'mpsc' channels with several producers.")
+        .tags(&["concurrency", "move"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch16_concurrency::move_closures", "ch13_iterators::consuming_adapters"])
+        .book("ch16-02-message-passing.html"),
+    example!(16, shared_state)
+        .summary("A counter in 'Arc<Mutex<T>>' shared by ten threads.")
+        .tags(&["concurrency", "smart-pointers", "mutability"])
+        .difficulty(Difficulty::Advanced)
+        .requires(&["ch16_concurrency::move_closures", "ch15_smart_pointers::rc_refcell"])
+        .book("ch16-03-shared-state.html"),
+    example!(16, scoped_threads)
+        .summary("'thread::scope' lets threads borrow local data.")
+        .tags(&["concurrency", "borrowing"])
+        .difficulty(Difficulty::Advanced)
+        .requires(&["ch16_concurrency::shared_state", "ch4_ownership::switch_between_references"])
+        .book("ch16-04-extensible-concurrency-sync-and-send.html"),
+];
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // threads run in a different order on each run: the examples must pass
+    // whatever the order, not only the first time.
+    #[test]
+    fn examples_hold_in_any_order() {
+        for _ in 0..100 {
+            spawn_and_join();
+            move_closures();
+            message_passing();
+            shared_state();
+            scoped_threads();
+        }
+    }
+}
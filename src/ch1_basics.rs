@@ -1,4 +1,28 @@
 
+//! Chapter 1: variables, mutability, constants and scope.
+//!
+//! See <https://doc.rust-lang.org/book/ch03-01-variables-and-mutability.html>.
+
+
+/// Bindings are immutable by default: a new value is either given to a new
+/// binding of the same name (shadowing), or the binding is declared 'mut'.
+///
+/// ```
+/// let x: i32 = 5;
+/// let x: i32 = x + 3; // shadows the first 'x'
+/// assert_eq!(x, 8);
+///
+/// let mut m: i32 = 5;
+/// m += 3;
+/// assert_eq!(m, 8);
+/// ```
+///
+/// Assigning twice to an immutable binding is rejected:
+///
+/// ```compile_fail,E0384
+/// let x: i32 = 5;
+/// x = x + 3;
+/// ```
 pub fn variable() {
 
     // Variable declaration. Type-annotation is optional.
@@ -6,7 +30,8 @@ pub fn variable() {
 
 
     // 'x = x + 3' will cause an error because variables are by default 
-    // immutable. We can create a new variable named 'x' to shadow the old one.
+    // immutable (see compile_fail/ch1_basics/reassign_immutable.rs). We can 
+    // create a new variable named 'x' to shadow the old one.
     let x: i32 = x + 3;
     assert_eq!(x, 8); // '!' stands for a macro.
 
@@ -22,7 +47,16 @@ pub fn variable() {
 }
 
 
-// variables have their own (explicit) scope.
+/// variables have their own (explicit) scope.
+///
+/// ```
+/// let v: i32 = 233;
+/// {
+///     let v: i32 = 666; // only shadows 'v' until the end of the block
+///     assert_eq!(v, 666);
+/// }
+/// assert_eq!(v, 233);
+/// ```
 pub fn scope() {
     let v: i32 = 233;
     {
@@ -31,3 +65,37 @@ pub fn scope() {
     }
     assert_eq!(v, 233);
 }
+
+
+use crate::registry::{example, Difficulty, Example};
+
+pub const EXAMPLES: &[Example] = &[
+    example!(1, variable)
+        .summary("Bindings are immutable by default; shadow them or use 'mut'.")
+        .tags(&["variables", "mutability", "shadowing"])
+        .difficulty(Difficulty::Beginner)
+        .book("ch03-01-variables-and-mutability.html"),
+    example!(1, scope)
+        .summary("An inner block can shadow a binding without touching the outer one.")
+        .tags(&["variables", "scope", "shadowing"])
+        .difficulty(Difficulty::Beginner)
+        .requires(&["ch1_basics::variable"])
+        .book("ch03-01-variables-and-mutability.html#shadowing"),
+];
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the examples check themselves: calling one runs its assertions.
+    #[test]
+    fn variable_shadows_and_mutates() {
+        variable();
+    }
+
+    #[test]
+    fn scope_restores_outer_binding() {
+        scope();
+    }
+}
@@ -1,5 +1,17 @@
+//! Chapter 2: scalar and compound data types.
+//!
+//! See <https://doc.rust-lang.org/book/ch03-02-data-types.html>.
 
-// Numeric types are intuitive. Operations include + - * / %.
+
+/// Numeric types are intuitive. Operations include + - * / %.
+///
+/// ```
+/// assert_eq!(0xdeadbeef_i64, 3735928559);
+/// assert_eq!(0o233 + 0b0011010, 181);
+/// assert_eq!(b'?', 63_u8);
+/// assert_eq!(7 / 2, 3); // integer division truncates
+/// assert_eq!(7.0 / 2.0, 3.5);
+/// ```
 pub fn numeric() {
     let _b: bool = true;
     let _x: i32 = 233;
@@ -12,7 +24,14 @@ pub fn numeric() {
 }
 
 
-// tuples work similar to that of python.
+/// tuples work similar to that of python.
+///
+/// ```
+/// let tup: (i32, f64, char) = (100, 6.6, 'x');
+/// let (x, _, z) = tup; // destructuring
+/// assert_eq!((x, z), (100, 'x'));
+/// assert_eq!(tup.1, 6.6);
+/// ```
 pub fn tuple() {
     let tup: (i32, i32) = (100, 66);
     let (_x, y): (i32, i32) = tup;
@@ -21,7 +40,15 @@ pub fn tuple() {
 }
 
 
-// arrays are also intuitive
+/// arrays are also intuitive
+///
+/// ```
+/// let mut arr: [i32; 3] = [233; 3]; // three times 233
+/// arr[0] = 666;
+/// assert_eq!(arr, [666, 233, 233]);
+/// assert_eq!(arr.len(), 3);
+/// assert_eq!(arr.get(3), None); // 'arr[3]' would panic instead
+/// ```
 pub fn array() {
     let arr2: [i32; 6] = [233,233,233,233,233,233];
     let mut arr3: [i32; 6] = [233; 6];
@@ -32,3 +59,49 @@ pub fn array() {
     arr3[0] = 666;
     assert_eq!(arr3[0], 666);   
 }
+
+
+use crate::registry::{example, Difficulty, Example};
+
+pub const EXAMPLES: &[Example] = &[
+    example!(2, numeric)
+        .summary("Integer, float, bool and byte literals with explicit types.")
+        .tags(&["types", "scalars"])
+        .difficulty(Difficulty::Beginner)
+        .requires(&["ch1_basics::variable"])
+        .book("ch03-02-data-types.html#scalar-types"),
+    example!(2, tuple)
+        .summary("Building tuples, destructuring them and indexing with '.0'.")
+        .tags(&["types", "tuples", "destructuring"])
+        .difficulty(Difficulty::Beginner)
+        .requires(&["ch2_datatypes::numeric"])
+        .book("ch03-02-data-types.html#the-tuple-type"),
+    example!(2, array)
+        .summary("Fixed-size arrays, repeat initializers and indexing.")
+        .tags(&["types", "arrays"])
+        .difficulty(Difficulty::Beginner)
+        .requires(&["ch2_datatypes::numeric"])
+        .book("ch03-02-data-types.html#the-array-type"),
+];
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the examples check themselves: calling one runs its assertions.
+    #[test]
+    fn numeric_literals() {
+        numeric();
+    }
+
+    #[test]
+    fn tuple_destructuring() {
+        tuple();
+    }
+
+    #[test]
+    fn array_repeat_expression() {
+        array();
+    }
+}
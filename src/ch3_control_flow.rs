@@ -1,10 +1,28 @@
 
-pub fn function_call() {
-    // Return type annotation is mandatory except when nothing is returned
-    fn plus_one(num: i32) -> i32 {
-        num + 1
-    }
+//! Chapter 3: functions and control flow.
+//!
+//! See <https://doc.rust-lang.org/book/ch03-03-how-functions-work.html> and
+//! <https://doc.rust-lang.org/book/ch03-05-control-flow.html>.
+
+
+/// Return type annotation is mandatory except when nothing is returned
+///
+/// ```
+/// use syntax_examples::ch3_control_flow::plus_one;
+///
+/// assert_eq!(plus_one(-1), 0);
+/// ```
+pub fn plus_one(num: i32) -> i32 {
+    num + 1
+}
 
+/// Calls 'plus_one'. Methods take their receiver by reference automatically:
+///
+/// ```
+/// let s: String = String::from("bob");
+/// assert_eq!(s.len(), (&s).len());
+/// ```
+pub fn function_call() {
     let mut x: i32 = 0;
     x = plus_one(x);
     assert_eq!(x, 1);
@@ -13,11 +31,24 @@ pub fn function_call() {
     // note that rust implements auto referencing / dereferencing for fn calls.
     let s: String = String::from("bob");
     let _l: usize = s.len(); // valid
+    #[allow(clippy::needless_borrow)]
     let _l: usize = (&s).len(); // also valid
 }
 
 
-// 'if' works like usual except that it can be treated as a value
+/// 'if' works like usual except that it can be treated as a value
+///
+/// ```
+/// let n: i32 = -4;
+/// let sign: &str = if n < 0 {"negative"} else {"non-negative"};
+/// assert_eq!(sign, "negative");
+/// ```
+///
+/// Both branches must then have the same type:
+///
+/// ```compile_fail,E0308
+/// let x = if true {3} else {"four"};
+/// ```
 pub fn if_syntax() {
     // as a procedure
     if true {
@@ -32,7 +63,16 @@ pub fn if_syntax() {
 }
 
 
-// 'loop' never ends unless 'break' is reached. It's also a value.
+/// 'loop' never ends unless 'break' is reached. It's also a value.
+///
+/// ```
+/// let mut x: i32 = 3;
+/// let y: i32 = loop {
+///     if x >= 10 {break x * 2;}
+///     x += 1;
+/// };
+/// assert_eq!(y, 20);
+/// ```
 pub fn loop_syntax() {
 
     let mut x: i32 = 3;
@@ -49,7 +89,20 @@ pub fn loop_syntax() {
 }
 
 
-// while and for loops are intuitive.
+/// while and for loops are intuitive.
+///
+/// ```
+/// let mut sum: i32 = 0;
+/// for n in 1..4 { // 1, 2 and 3: the end is excluded
+///     sum += n;
+/// }
+/// assert_eq!(sum, 6);
+///
+/// while sum > 0 {
+///     sum -= 4;
+/// }
+/// assert_eq!(sum, -2);
+/// ```
 pub fn for_and_while() {
     
     let mut x: i32 = 10;
@@ -60,13 +113,72 @@ pub fn for_and_while() {
     assert_eq!(x, 0);
 
     let mut a: [i32; 3] = [233, 666, 999];
-    for element in a {
+    for element in a { // calls 'next' on 'a.into_iter()' (see 'ch13_iterators')
         x += element;
     }
     assert_eq!(x, 233 + 666 + 999);
 
+    #[allow(clippy::needless_range_loop)]
     for index in 0..3 { // syntax: 'inclusive..exclusive'.
         a[index] = index as i32; // explicitly cast from usize to i32
     }
     assert_eq!(a, [0,1,2]);
 }
+
+
+use crate::registry::{example, Difficulty, Example};
+
+pub const EXAMPLES: &[Example] = &[
+    example!(3, function_call)
+        .summary("Function signatures, return values and automatic referencing.")
+        .tags(&["functions"])
+        .difficulty(Difficulty::Beginner)
+        .requires(&["ch1_basics::variable"])
+        .shows(&["plus_one"])
+        .book("ch03-03-how-functions-work.html"),
+    example!(3, if_syntax)
+        .summary("'if' as a statement and as a value.")
+        .tags(&["control-flow", "expressions"])
+        .difficulty(Difficulty::Beginner)
+        .requires(&["ch1_basics::variable"])
+        .book("ch03-05-control-flow.html#if-expressions"),
+    example!(3, loop_syntax)
+        .summary("'loop' runs until 'break', which can return a value.")
+        .tags(&["control-flow", "loops", "expressions"])
+        .difficulty(Difficulty::Beginner)
+        .requires(&["ch3_control_flow::if_syntax"])
+        .book("ch03-05-control-flow.html#returning-values-from-loops"),
+    example!(3, for_and_while)
+        .summary("'while' loops, 'for' over arrays and over ranges.")
+        .tags(&["control-flow", "loops"])
+        .difficulty(Difficulty::Beginner)
+        .requires(&["ch3_control_flow::loop_syntax", "ch2_datatypes::array"])
+        .book("ch03-05-control-flow.html#looping-through-a-collection-with-for"),
+];
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plus_one_values() {
+        assert_eq!(plus_one(0), 1);
+        assert_eq!(plus_one(-1), 0);
+        assert_eq!(plus_one(i32::MAX - 1), i32::MAX);
+    }
+
+    // overflow is only checked in debug builds; release builds wrap around.
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn plus_one_overflows() {
+        plus_one(i32::MAX);
+    }
+
+    #[test]
+    fn loops() {
+        loop_syntax();
+        for_and_while();
+    }
+}
@@ -1,16 +1,23 @@
 
-// I suggest reading this chaper from The Book. 
-// This is more conceptual than syntactic.
-// https://doc.rust-lang.org/book/ch04-00-understanding-ownership.html
-
-
-// Rust's Ownership Model: 
-//  * Each value in Rust has an owner
-//  * There can only be one owner at a time
-//  * When an owner goes out of scope, the value is dropped (becomes invalid)
-
-
-// for things on heap, rust has shallow transfer instead of shallow copy.
+//! Chapter 4: ownership, borrowing and slices.
+//!
+//! I suggest reading this chaper from The Book. 
+//! This is more conceptual than syntactic.
+//! <https://doc.rust-lang.org/book/ch04-00-understanding-ownership.html>
+//!
+//! Rust's Ownership Model: 
+//!  * Each value in Rust has an owner
+//!  * There can only be one owner at a time
+//!  * When an owner goes out of scope, the value is dropped (becomes invalid)
+
+
+/// for things on heap, rust has shallow transfer instead of shallow copy.
+///
+/// ```compile_fail,E0382
+/// let a: String = String::from("bob");
+/// let b: String = a; // 'a' moves into 'b'
+/// println!("{} {}", a, b);
+/// ```
 pub fn shallow_transfer() {
 
     // rust's "shallow copy" of object is actually "move"
@@ -20,11 +27,18 @@ pub fn shallow_transfer() {
     // at this point _a becomes invalid, because the ownership of the string is 
     // transferred to _b during the assignment, instead of having both pointers 
     // own the same heap memory. Eliminates a whole class of bug. Hooray!
+    // (see compile_fail/ch4_ownership/use_after_move.rs)
 
 }
 
 
-// rust's deep copy is intuitive
+/// rust's deep copy is intuitive
+///
+/// ```
+/// let a: String = String::from("bob");
+/// let b: String = a.clone();
+/// assert_eq!(a, b); // both are still valid
+/// ```
 pub fn deep_copy() {
 
     let _b: String = String::from("bob");
@@ -35,7 +49,13 @@ pub fn deep_copy() {
 }
 
 
-// copy works straightforward for things on stack though
+/// copy works straightforward for things on stack though
+///
+/// ```
+/// let m: i32 = 50;
+/// let n: i32 = m; // 'i32' is 'Copy', so 'm' stays valid
+/// assert_eq!(m, n);
+/// ```
 pub fn stack_copy() {
     let m: i32 = 50;
     let n: i32 = m;
@@ -45,6 +65,18 @@ pub fn stack_copy() {
 }
 
 
+/// Passing a value to a function moves it, just like an assignment; values
+/// that are 'Copy' are copied instead.
+///
+/// ```compile_fail,E0382
+/// fn takes_ownership(s: String) {
+///     println!("{s}");
+/// }
+///
+/// let s: String = String::from("bob");
+/// takes_ownership(s);
+/// println!("{s}"); // 's' was moved into the function
+/// ```
 pub fn ownership_through_function() {
 
     // rust automatically drops values that are out of scope.
@@ -79,8 +111,27 @@ pub fn ownership_through_function() {
 }
 
 
-// creating a reference  to a valueis called "borrowing": we make use of the  
-// value without taking its ownership. 
+/// creating a reference  to a valueis called "borrowing": we make use of the  
+/// value without taking its ownership. 
+///
+/// ```
+/// fn calculate_length(s: &str) -> usize {
+///     s.len()
+/// }
+///
+/// let s: String = String::from("bob");
+/// assert_eq!(calculate_length(&s), 3);
+/// assert_eq!(s, "bob"); // 's' is still ours
+/// ```
+///
+/// A value cannot move while it is borrowed:
+///
+/// ```compile_fail,E0505
+/// let s: String = String::from("bob");
+/// let r: &String = &s;
+/// let t: String = s;
+/// println!("{} {}", r, t);
+/// ```
 pub fn immutable_references() {
 
     let s: String = String::from("bob"); // create value
@@ -103,6 +154,7 @@ pub fn immutable_references() {
 
 
     // borrowing
+    #[allow(clippy::ptr_arg)] // '&str' is more idiomatic, see 'slice()'.
     fn calculate_length(s: &String) -> usize {
         s.len()
     }
@@ -114,8 +166,18 @@ pub fn immutable_references() {
 }
 
 
-// Borrowing has an issue: you cannot modify borrowed stuff via its reference. 
-// Thus we will need mutable references instead.
+/// Borrowing has an issue: you cannot modify borrowed stuff via its reference. 
+/// Thus we will need mutable references instead.
+///
+/// ```
+/// fn change(s: &mut String) {
+///     s.push_str(" likes alice");
+/// }
+///
+/// let mut bob_name: String = String::from("bob");
+/// change(&mut bob_name);
+/// assert_eq!(bob_name, "bob likes alice");
+/// ```
 pub fn mutable_references() {
 
     // must declare 'bob_name' as mutable in order to borrow it as mutable
@@ -133,35 +195,54 @@ pub fn mutable_references() {
     }
 }
 
-// Mutable references has a restriction: when there's a mutable reference to a 
-// value, no other references (mutable or not) to such value are allowed. 
-// 
-// This makes sense: immutable references can be thought of as having permission
-// for reading, and mutable references can be thought of as having permission 
-// for both reading and writing. To prevent a race condition, a piece of data 
-// can have multiple readers; but when a writer exists, nothing else should 
-// access the data. 
-// 
-// Summary: we can one of the following to a value:
-//  * no reference, 
-//  * arbitrarily many immutable reference (ie. 'read mode'), or
-//  * only one mutable reference (ie. 'write mode').
-//
-
-
-// What if we have created some immutable refs, we are done using them, and now 
-// we want mutable refs? How can we get rid of the old immutable refs? They 
-// cannot coexist, can they?
-// 
-// For now, we can interpret them as an automatic process: once we create a 
-// mutable ref, all previous immutable refs are automatically dropped; and once 
-// we start creating immutable refs, the existing mutable ref (if there is one) 
-// is automatically dropped. 
-//
-// One tip from author: manage the references as if you were managing 
-// read / write concurrency of a program! For readers who are familiar with 
-// C / C++, it would be nice to make an analogy to shared mutex.
-//
+/// Mutable references has a restriction: when there's a mutable reference to a 
+/// value, no other references (mutable or not) to such value are allowed. 
+/// 
+/// This makes sense: immutable references can be thought of as having permission
+/// for reading, and mutable references can be thought of as having permission 
+/// for both reading and writing. To prevent a race condition, a piece of data 
+/// can have multiple readers; but when a writer exists, nothing else should 
+/// access the data. 
+/// 
+/// Summary: we can one of the following to a value:
+///  * no reference, 
+///  * arbitrarily many immutable reference (ie. 'read mode'), or
+///  * only one mutable reference (ie. 'write mode').
+///
+/// What if we have created some immutable refs, we are done using them, and now 
+/// we want mutable refs? How can we get rid of the old immutable refs? They 
+/// cannot coexist, can they?
+/// 
+/// For now, we can interpret them as an automatic process: once we create a 
+/// mutable ref, all previous immutable refs are automatically dropped; and once 
+/// we start creating immutable refs, the existing mutable ref (if there is one) 
+/// is automatically dropped. 
+///
+/// One tip from author: manage the references as if you were managing 
+/// read / write concurrency of a program! For readers who are familiar with 
+/// C / C++, it would be nice to make an analogy to shared mutex (real threads
+/// and mutexes are in 'ch16_concurrency').
+///
+/// ```
+/// let mut s: String = String::from("bob");
+/// let r1: &String = &s;
+/// let r2: &String = &s;
+/// assert_eq!(r1, r2); // last use of 'r1' and 'r2'
+///
+/// let w: &mut String = &mut s;
+/// w.push('!');
+/// assert_eq!(s, "bob!");
+/// ```
+///
+/// Two mutable references that are both in use are rejected:
+///
+/// ```compile_fail,E0499
+/// let mut s: String = String::from("bob");
+/// let w1: &mut String = &mut s;
+/// let w2: &mut String = &mut s;
+/// w1.push('!');
+/// w2.push('?');
+/// ```
 pub fn switch_between_references() {
     let mut bob_name: String = String::from("bob");
 
@@ -179,6 +260,7 @@ pub fn switch_between_references() {
     // Create mutable ref. 'immut_1' and 'immut_2' gets dropped at this point.
     let mutab_1: &mut String = &mut bob_name;
     // note: we cannot create a second mutable ref.
+    // (see compile_fail/ch4_ownership/two_mutable_borrows.rs)
 
     change(mutab_1); // use mutable ref
 
@@ -189,6 +271,7 @@ pub fn switch_between_references() {
 
 
     // helper functions
+    #[allow(clippy::ptr_arg)]
     fn take_ownership(s: &String) -> usize {
         s.len()
     }
@@ -200,6 +283,16 @@ pub fn switch_between_references() {
 }
 
 
+/// Slices borrow a contiguous part of a string or an array.
+///
+/// ```
+/// let s: &str = "hello rust lang";
+/// assert_eq!(&s[6..10], "rust");
+/// assert_eq!(&s[11..], "lang");
+///
+/// let a: [i32; 5] = [1, 2, 3, 4, 5];
+/// assert_eq!(&a[1..4], &[2, 3, 4]);
+/// ```
 pub fn slice() {
     
     // slices work similar to python slicing.
@@ -219,6 +312,15 @@ pub fn slice() {
 }
 
 
+/// A slice borrows its source, which therefore cannot be modified while the
+/// slice is in use.
+///
+/// ```compile_fail,E0502
+/// let mut s: String = String::from("bob likes alice");
+/// let word: &str = &s[..3];
+/// s.clear();
+/// println!("{}", word);
+/// ```
 pub fn slice_ownership() {
 
     let mut s: String = String::from("bob likes alice"); // create mutable var
@@ -228,18 +330,124 @@ pub fn slice_ownership() {
     s.clear(); // mutable borrow occurs, previous immutable ref becomes invalid
 
     // can no longer use '_word' here.
+    // (see compile_fail/ch4_ownership/clear_while_borrowed.rs)
 
     // remark: rust auto cleans up references to invalid (ie. freed) data.
+}
+
+
+/// helper function of 'slice_ownership': the text up to the first space.
+///
+/// ```
+/// use syntax_examples::ch4_ownership::first_word;
+///
+/// assert_eq!(first_word(&"bob likes alice".to_string()), "bob");
+/// assert_eq!(first_word(&"bob".to_string()), "bob");
+/// ```
+#[allow(clippy::ptr_arg)] // '&str' would also accept string literals
+pub fn first_word(s: &String) -> &str {
+    let bytes = s.as_bytes();
+
+    for (i, &item) in bytes.iter().enumerate() {
+        if item == b' ' {return &s[..i];}
+    }
+
+    &s[..]
+}
 
 
-    // helper function
-    fn first_word(s: &String) -> &str {
-        let bytes = s.as_bytes();
+use crate::registry::{example, Difficulty, Example};
+
+pub const EXAMPLES: &[Example] = &[
+    example!(4, shallow_transfer)
+        .summary("Assigning a heap value moves it; the old binding becomes invalid.")
+        .tags(&["ownership", "move"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch1_basics::scope"])
+        .book("ch04-01-what-is-ownership.html#variables-and-data-interacting-with-move"),
+    example!(4, deep_copy)
+        .summary("'clone' duplicates heap data so both bindings stay valid.")
+        .tags(&["ownership", "clone"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch4_ownership::shallow_transfer"])
+        .book("ch04-01-what-is-ownership.html#variables-and-data-interacting-with-clone"),
+    example!(4, stack_copy)
+        .summary("Stack-only values are copied instead of moved.")
+        .tags(&["ownership", "copy"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch4_ownership::shallow_transfer"])
+        .book("ch04-01-what-is-ownership.html#stack-only-data-copy"),
+    example!(4, ownership_through_function)
+        .summary("Passing a value to a function moves or copies it.")
+        .tags(&["ownership", "move", "copy", "functions"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&[
+            "ch4_ownership::shallow_transfer",
+            "ch3_control_flow::function_call",
+        ])
+        .book("ch04-01-what-is-ownership.html#ownership-and-functions"),
+    example!(4, immutable_references)
+        .summary("Borrowing with '&' uses a value without taking ownership.")
+        .tags(&["ownership", "borrowing", "references"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch4_ownership::ownership_through_function"])
+        .book("ch04-02-references-and-borrowing.html"),
+    example!(4, mutable_references)
+        .summary("Borrowing with '&mut' allows modifying the borrowed value.")
+        .tags(&["ownership", "borrowing", "references", "mutability"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch4_ownership::immutable_references"])
+        .book("ch04-02-references-and-borrowing.html#mutable-references"),
+    example!(4, switch_between_references)
+        .summary("Many readers or one writer: alternating '&' and '&mut' borrows.")
+        .tags(&["ownership", "borrowing", "references", "mutability"])
+        .difficulty(Difficulty::Advanced)
+        .requires(&["ch4_ownership::mutable_references"])
+        .book("ch04-02-references-and-borrowing.html#mutable-references"),
+    example!(4, slice)
+        .summary("String and array slices with range syntax.")
+        .tags(&["slices", "strings", "arrays"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch4_ownership::immutable_references", "ch2_datatypes::array"])
+        .book("ch04-03-slices.html"),
+    example!(4, slice_ownership)
+        .summary("A slice borrows its source, so the source cannot be cleared meanwhile.")
+        .tags(&["ownership", "borrowing", "slices"])
+        .difficulty(Difficulty::Advanced)
+        .requires(&["ch4_ownership::slice", "ch4_ownership::mutable_references"])
+        .shows(&["first_word"])
+        .book("ch04-03-slices.html#string-slices"),
+];
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_word_of_sentence() {
+        assert_eq!(first_word(&"bob likes alice".to_string()), "bob");
+        assert_eq!(first_word(&"héllo wörld".to_string()), "héllo");
+    }
 
-        for (i, &item) in bytes.iter().enumerate() {
-            if item == b' ' {return &s[..i];}
-        }
+    #[test]
+    fn first_word_edge_cases() {
+        assert_eq!(first_word(&String::new()), "");
+        assert_eq!(first_word(&"bob".to_string()), "bob"); // no space at all
+        assert_eq!(first_word(&" bob".to_string()), ""); // leading space
+        assert_eq!(first_word(&"bob ".to_string()), "bob");
+    }
+
+    #[test]
+    fn references() {
+        immutable_references();
+        mutable_references();
+        switch_between_references();
+    }
 
-        &s[..]
+    #[test]
+    fn slices() {
+        slice();
+        slice_ownership(); // through 'first_word'
     }
 }
@@ -1,10 +1,35 @@
 
-pub fn struct_syntax() {
+//! Chapter 5: structs and their methods.
+//!
+//! See <https://doc.rust-lang.org/book/ch05-00-structs.html>.
+
+
+/// A struct with named fields.
+///
+/// ```
+/// use syntax_examples::ch5_struct::Point;
+///
+/// let p = Point{x_coord: 1.0, y_coord: 2.0};
+/// let q = Point{y_coord: 5.0, ..p}; // struct update syntax
+/// assert_eq!((q.x_coord, q.y_coord), (1.0, 5.0));
+/// ```
+pub struct Point {
+    pub x_coord: f32,
+    pub y_coord: f32,
+}
 
-    struct Point {
-        x_coord: f32,
-        y_coord: f32,
-    }
+/// Builds and updates a 'Point'; fields can be listed in any order, and a
+/// field can be initialized from a variable of the same name.
+///
+/// ```
+/// use syntax_examples::ch5_struct::Point;
+///
+/// fn build_point(x_coord: f32, y_coord: f32) -> Point {
+///     Point{x_coord, y_coord} // field init shorthand
+/// }
+/// assert_eq!(build_point(1.0, 2.0).y_coord, 2.0);
+/// ```
+pub fn struct_syntax() {
 
     let _q: Point = Point{x_coord: 0.0, y_coord: 0.0};
 
@@ -14,6 +39,7 @@ pub fn struct_syntax() {
     };
 
     p.y_coord = 6666.6;
+    assert_eq!(p.x_coord + p.y_coord, 233.3 + 6666.6);
 
     fn build_point(x_coord: f32, y_coord: f32) -> Point {
         Point{x_coord, y_coord}
@@ -21,15 +47,31 @@ pub fn struct_syntax() {
 
     let mut r: Point = build_point(0 as f32, 0 as f32);
     r.x_coord = 2333.3;
+    assert_eq!(r.x_coord + r.y_coord, 2333.3);
 
 }
 
+/// Tuple structs, unit-like structs, and structs that derive 'Debug'.
+///
+/// ```
+/// struct ColorRGB(u8, u8, u8);
+/// let green = ColorRGB(0, 255, 0);
+/// assert_eq!(green.1, 255);
+///
+/// #[derive(Debug)]
+/// struct Student {
+///     name: String,
+/// }
+/// let bob = Student{name: String::from("bob")};
+/// assert_eq!(format!("{:?}", bob), "Student { name: \"bob\" }");
+/// ```
 pub fn special_struct() {
 
     // tuple structs without named fields; 
     // note the round brackets instead of curly.
     struct ColorRGB(u8, u8, u8);
-    let _green = ColorRGB(0, 255, 0);
+    let green = ColorRGB(0, 255, 0);
+    assert_eq!(green.0 + green.1 + green.2, 255); // fields are numbered.
 
 
     // unit-like struct without any fields
@@ -47,29 +89,92 @@ pub fn special_struct() {
 
 }
 
-// struct lifetimes are skipped till later.
-
-pub fn struct_method() {
+// structs holding references are covered in 'ch10_generics::struct_lifetime'.
+
+/// ```
+/// use syntax_examples::ch5_struct::Rectangle;
+///
+/// let r = Rectangle{width: 5, height: 3};
+/// assert_eq!(r.area(), 15);
+/// assert_eq!(r.transpose().width, 3);
+/// ```
+pub struct Rectangle{
+    pub width: u32, 
+    pub height: u32, 
+}
 
-    struct Rectangle{
-        width: u32, 
-        height: u32, 
+// implementations of struct methods can span across several 'impl' blocks.
+impl Rectangle {
+    pub fn area(&self) -> u32 {
+        self.width * self.height
     }
 
-    // implementations of struct methods can span across several 'impl' blocks.
-    impl Rectangle {
-        fn area(&self) -> u32 {
-            self.width * self.height
-        }
-
-        fn transpose(&self) -> Self {
-            Self{ // 'Self' refers to type of self, in this case 'Rectangle'.
-                width: self.height, 
-                height: self.width,
-            }
+    pub fn transpose(&self) -> Self {
+        Self{ // 'Self' refers to type of self, in this case 'Rectangle'.
+            width: self.height, 
+            height: self.width,
         }
     }
+}
+
+/// Calls the methods of 'Rectangle'; 'r.area()' is sugar for
+/// 'Rectangle::area(&r)'.
+pub fn struct_method() {
 
     let r = Rectangle{width: 5, height: 3};
     assert_eq!(r.area(), r.transpose().area());
 }
+
+
+use crate::registry::{example, Difficulty, Example};
+
+pub const EXAMPLES: &[Example] = &[
+    example!(5, struct_syntax)
+        .summary("Defining, building and updating a struct with named fields.")
+        .tags(&["structs"])
+        .difficulty(Difficulty::Beginner)
+        .requires(&["ch3_control_flow::function_call"])
+        .shows(&["Point"])
+        .book("ch05-01-defining-structs.html"),
+    example!(5, special_struct)
+        .summary("Tuple structs, unit-like structs and '#[derive(Debug)]' printing.")
+        .tags(&["structs", "traits"])
+        .difficulty(Difficulty::Beginner)
+        .requires(&["ch5_struct::struct_syntax", "ch2_datatypes::tuple"])
+        .book("ch05-01-defining-structs.html#using-tuple-structs-without-named-fields-to-create-different-types"),
+    example!(5, struct_method)
+        .summary("Methods in 'impl' blocks, '&self' and 'Self'.")
+        .tags(&["structs", "methods"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch5_struct::struct_syntax", "ch4_ownership::immutable_references"])
+        .shows(&["Rectangle"])
+        .book("ch05-03-method-syntax.html"),
+];
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rectangle_area() {
+        let r = Rectangle{width: 5, height: 3};
+        assert_eq!(r.area(), 15);
+
+        let t: Rectangle = r.transpose();
+        assert_eq!((t.width, t.height), (3, 5));
+        assert_eq!(t.transpose().width, r.width);
+    }
+
+    #[test]
+    fn degenerate_rectangle() {
+        let line = Rectangle{width: 0, height: 7};
+        assert_eq!(line.area(), 0);
+        assert_eq!(line.transpose().area(), 0);
+    }
+
+    #[test]
+    fn point_fields() {
+        struct_syntax();
+    }
+}
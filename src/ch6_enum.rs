@@ -1,7 +1,27 @@
 
+//! Chapter 6: enums, 'Option', 'match' and 'if let'.
+//!
+//! See <https://doc.rust-lang.org/book/ch06-00-enums.html>.
+
+
+/// Enum variants can carry data of their own.
+///
+/// ```
+/// enum Money {
+///     Quarter,
+///     CustomCheck(u32),
+/// }
+///
+/// let cents = |money: Money| match money {
+///     Money::Quarter => 25,
+///     Money::CustomCheck(amount) => amount,
+/// };
+/// assert_eq!(cents(Money::Quarter) + cents(Money::CustomCheck(100)), 125);
+/// ```
 pub fn enum_syntax() {
 
     // enum can take arguments
+    #[allow(dead_code)]
     enum Money {
         _Dime, 
         Quarter, 
@@ -22,41 +42,128 @@ pub fn enum_syntax() {
 use std::cmp::max;
 
 
-// in general, one can use 'match' to deconstruct enum.
+/// the larger of two optional values, if any.
+///
+/// ```
+/// use syntax_examples::ch6_enum::opt_max;
+///
+/// assert_eq!(opt_max(Some(233), Some(666)), Some(666));
+/// assert_eq!(opt_max(None, Some(3)), Some(3));
+/// assert_eq!(opt_max(None, None), None);
+/// ```
+pub fn opt_max(x: Option<i32>, y: Option<i32>) -> Option<i32> {
+    match (x, y) {
+        (Some(a), Some(b)) => Some(max(a, b)), 
+        (_, None) => x, 
+        _ => y,
+    }
+}
+
+/// in general, one can use 'match' to deconstruct enum.
+///
+/// The arms must cover every case:
+///
+/// ```compile_fail,E0004
+/// let x: Option<i32> = Some(666);
+/// let y: i32 = match x {
+///     Some(n) => n,
+/// };
+/// ```
 pub fn match_syntax() {
 
     let x: Option<i32> = Some(666); // Option<i32> is like 'int option' in ml.
     let y: Option<i32> = None;
 
-    fn opt_max(x: Option<i32>, y: Option<i32>) -> Option<i32> {
-        match (x, y) {
-            (Some(a), Some(b)) => Some(max(a, b)), 
-            (_, None) => x, 
-            _ => y,
-        }
-    }
-
     assert_eq!(x, opt_max(Some(233), opt_max(x, y)));
     assert_eq!(None, opt_max(None, None));
 }
 
 
-// 'if let' syntax is an alternative to 'match'
+/// same as 'opt_max', with 'if let' instead of 'match'.
+///
+/// ```
+/// use syntax_examples::ch6_enum::{opt_max, opt_max_if_let};
+///
+/// for (x, y) in [(Some(1), Some(2)), (Some(1), None), (None, Some(2)), (None, None)] {
+///     assert_eq!(opt_max_if_let(x, y), opt_max(x, y));
+/// }
+/// ```
+pub fn opt_max_if_let(x: Option<i32>, y: Option<i32>) -> Option<i32> {
+    if let (Some(a), Some(b)) = (x, y) {
+        Some(max(a, b))
+    } else if let (_, None) = (x, y) {
+        x
+    } else {
+        y
+    }
+}
+
+/// 'if let' syntax is an alternative to 'match'
+///
+/// ```
+/// let x: Option<i32> = Some(666);
+/// if let Some(n) = x {
+///     assert_eq!(n, 666);
+/// } else {
+///     unreachable!();
+/// }
+/// ```
 pub fn iflet_syntax() {
 
     let x: Option<i32> = Some(666);
     let y: Option<i32> = None;
 
-    fn opt_max(x: Option<i32>, y: Option<i32>) -> Option<i32> {
-        if let (Some(a), Some(b)) = (x, y) {
-            Some(max(a, b))
-        } else if let (_, None) = (x, y) {
-            x
-        } else {
-            y
+    assert_eq!(x, opt_max_if_let(Some(233), opt_max_if_let(x, y)));
+    assert_eq!(None, opt_max_if_let(None, None));
+}
+
+
+use crate::registry::{example, Difficulty, Example};
+
+pub const EXAMPLES: &[Example] = &[
+    example!(6, enum_syntax)
+        .summary("Enum variants with and without data, and the builtin Option.")
+        .tags(&["enums", "option"])
+        .difficulty(Difficulty::Beginner)
+        .requires(&["ch5_struct::struct_syntax"])
+        .book("ch06-01-defining-an-enum.html"),
+    example!(6, match_syntax)
+        .summary("Deconstructing a pair of Options with 'match'.")
+        .tags(&["enums", "option", "pattern-matching"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch6_enum::enum_syntax", "ch2_datatypes::tuple"])
+        .shows(&["opt_max"])
+        .book("ch06-02-match.html"),
+    example!(6, iflet_syntax)
+        .summary("The same logic as 'match_syntax', written with 'if let'.")
+        .tags(&["enums", "option", "pattern-matching"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch6_enum::match_syntax"])
+        .shows(&["opt_max_if_let"])
+        .book("ch06-03-if-let.html"),
+];
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // every combination of 'Some' and 'None', for both implementations.
+    #[test]
+    fn opt_max_combinations() {
+        for max_of in [opt_max, opt_max_if_let] {
+            assert_eq!(max_of(Some(1), Some(2)), Some(2));
+            assert_eq!(max_of(Some(2), Some(1)), Some(2));
+            assert_eq!(max_of(Some(-3), Some(-3)), Some(-3));
+            assert_eq!(max_of(Some(1), None), Some(1));
+            assert_eq!(max_of(None, Some(2)), Some(2));
+            assert_eq!(max_of(None, None), None);
         }
     }
 
-    assert_eq!(x, opt_max(Some(233), opt_max(x, y)));
-    assert_eq!(None, opt_max(None, None));
+    #[test]
+    fn opt_max_extremes() {
+        assert_eq!(opt_max(Some(i32::MIN), Some(i32::MAX)), Some(i32::MAX));
+        assert_eq!(opt_max_if_let(Some(i32::MIN), None), Some(i32::MIN));
+    }
 }
@@ -0,0 +1,251 @@
+//! Chapter 7: packages, crates, modules and visibility.
+//!
+//! Unlike the other chapters, this module is split over several files, the
+//! way The Book recommends for growing projects:
+//!
+//! ```text
+//! src/ch7_modules.rs                         this file
+//! src/ch7_modules/front_of_house.rs          'pub mod hosting;' and 'mod serving;'
+//! src/ch7_modules/front_of_house/hosting.rs
+//! src/ch7_modules/front_of_house/serving.rs
+//! src/ch7_modules/back_of_house.rs
+//! ```
+//!
+//! See <https://doc.rust-lang.org/book/ch07-00-managing-growing-projects-with-packages-crates-and-modules.html>.
+
+
+// 'mod name;' declares a module whose body lives in 'ch7_modules/name.rs'
+// (or in the older 'ch7_modules/name/mod.rs').
+pub mod front_of_house;
+pub mod back_of_house;
+
+
+// 'pub use' re-exports an item: 'ch7_modules::hosting' is now another path to
+// 'ch7_modules::front_of_house::hosting', usable by other crates too.
+pub use self::front_of_house::hosting;
+
+
+use std::collections::{self, BTreeMap, HashSet};
+use std::fmt::{self, Write as _};
+use self::back_of_house::{Appetizer, Breakfast};
+
+
+// Private to 'ch7_modules', and therefore visible to all of its descendants:
+// a child module sees every item of its ancestors.
+fn deliver_order(dish: &str) -> String {
+    format!("{dish}, delivered")
+}
+
+
+/// A package is a set of crates described by one 'Cargo.toml'. This package,
+/// 'syntax_examples', holds two crates: a library whose crate root is
+/// 'src/lib.rs', and a binary whose crate root is 'src/main.rs'. Every path
+/// of the library starts from its crate root, named 'crate' inside it and
+/// 'syntax_examples' outside.
+///
+/// ```
+/// // from another crate, such as this doctest
+/// use syntax_examples::ch7_modules::hosting;
+///
+/// assert_eq!(hosting::add_to_waitlist("bob"), "bob is waiting for one of 4 tables");
+/// ```
+pub fn packages_and_crates() {
+    assert_eq!(env!("CARGO_PKG_NAME"), "syntax_examples");
+    assert_eq!(env!("CARGO_CRATE_NAME"), "syntax_examples"); // the library
+
+    // module paths start with the name of the crate.
+    assert_eq!(module_path!(), "syntax_examples::ch7_modules");
+}
+
+
+/// Items are named by paths: absolute ones start from 'crate', relative ones
+/// from the current module, 'self' or its parent 'super'.
+pub fn module_paths() {
+
+    // absolute path
+    let a: String = crate::ch7_modules::front_of_house::hosting::add_to_waitlist("bob");
+
+    // relative path; 'self::' may be left out
+    let b: String = front_of_house::hosting::add_to_waitlist("bob");
+    let c: String = self::front_of_house::hosting::add_to_waitlist("bob");
+    assert_eq!(a, b);
+    assert_eq!(b, c);
+
+    // 'fix_incorrect_order' reaches 'deliver_order' with 'super::'.
+    assert_eq!(back_of_house::fix_incorrect_order(), "omelette, delivered");
+}
+
+
+/// Everything is private by default: an item is only visible in the module
+/// defining it and in that module's descendants. 'pub' makes it visible
+/// wherever its parent is, and 'pub(...)' makes it visible in part of the
+/// crate only:
+///  * 'pub(crate)' in the whole crate, but not to other crates;
+///  * 'pub(super)' in the parent module;
+///  * 'pub(in path)' in the given ancestor module.
+///
+/// ```compile_fail,E0603
+/// // 'seat_at_table' is 'pub(crate)', so other crates cannot call it.
+/// syntax_examples::ch7_modules::hosting::seat_at_table();
+/// ```
+pub fn visibility() {
+
+    // 'pub(crate)' and 'pub(in crate::ch7_modules)': visible here.
+    assert_eq!(hosting::seat_at_table(), 3);
+    assert_eq!(hosting::waitlist_len(), 2);
+
+    // 'serving' is a private module of 'front_of_house', and 'free_tables' is
+    // 'pub(super)' in 'hosting': only 'front_of_house' can use them. Public
+    // functions of 'front_of_house' give controlled access instead
+    // (see compile_fail/ch7_modules/private_module.rs).
+    assert_eq!(front_of_house::serve("soup"), "soup, served at one of 1 free tables");
+    assert_eq!(front_of_house::bill(), 12);
+}
+
+
+/// A public struct may have private fields; it can then only be built where
+/// those fields are visible, typically by a public constructor. Variants of
+/// a public enum are all public.
+///
+/// ```compile_fail,E0616
+/// use syntax_examples::ch7_modules::back_of_house::Breakfast;
+///
+/// let meal: Breakfast = Breakfast::summer("rye");
+/// println!("{}", meal.seasonal_fruit); // private field
+/// ```
+pub fn struct_visibility() {
+
+    let mut meal: Breakfast = Breakfast::summer("rye");
+    meal.toast = String::from("wheat"); // 'toast' is public
+    assert_eq!(meal.toast, "wheat");
+
+    // 'seasonal_fruit' is private: read it through a public method
+    // (see compile_fail/ch7_modules/private_field.rs), and building a
+    // Breakfast here with a struct literal is rejected
+    // (see compile_fail/ch7_modules/private_field_in_literal.rs).
+    assert_eq!(meal.fruit(), "peaches");
+
+    let _order_1: Appetizer = Appetizer::Soup;
+    let _order_2: Appetizer = Appetizer::Salad;
+}
+
+
+/// 'use' brings a path into scope once, instead of spelling it out at every
+/// use. Paths in 'use' start with 'crate', 'self', 'super' or a crate name.
+pub fn use_declarations() {
+
+    // the re-export 'ch7_modules::hosting' and the original path are the same
+    // module.
+    assert_eq!(hosting::location().0, front_of_house::hosting::location().0);
+
+    // nested paths: 'use std::collections::{self, BTreeMap, HashSet};' at the
+    // top brings 'collections' itself and two of its items into scope.
+    let mut menu: BTreeMap<&str, u32> = BTreeMap::new();
+    menu.insert("soup", 4);
+    let tags: HashSet<&str> = HashSet::from(["hot"]);
+    let queue: collections::VecDeque<&str> = collections::VecDeque::from(["bob"]);
+    assert_eq!((menu.len(), tags.len(), queue.len()), (1, 1, 1));
+
+    // 'as' renames an import; 'as _' imports a trait's methods without its
+    // name, here 'write!' on a String through 'fmt::Write'.
+    let mut bill: String = String::new();
+    write!(bill, "{} dollars", menu["soup"]).unwrap();
+    assert_eq!(bill, "4 dollars");
+    let _: fmt::Result = Ok(());
+
+    // a glob import brings every public item of a module into scope; it is
+    // mostly used in tests ('use super::*;') and preludes.
+    use self::back_of_house::*;
+    assert!(matches!(Appetizer::Salad, Appetizer::Salad));
+    assert_eq!(fix_incorrect_order(), "omelette, delivered");
+
+    // ...and globs nest too.
+    use std::{cmp::Ordering::*, iter::*};
+    assert_eq!(1.cmp(&2), Less);
+    assert_eq!(once(1).chain(empty()).sum::<i32>(), 1);
+}
+
+
+/// 'mod front_of_house;' loads 'src/ch7_modules/front_of_house.rs', which in
+/// turn declares 'pub mod hosting;', loaded from
+/// 'src/ch7_modules/front_of_house/hosting.rs'. The module tree is the same
+/// as if everything was written inline with 'mod hosting { ... }'.
+pub fn module_files() {
+    let (module, file) = hosting::location();
+    assert_eq!(module, "syntax_examples::ch7_modules::front_of_house::hosting");
+    assert_eq!(file, "src/ch7_modules/front_of_house/hosting.rs");
+}
+
+
+use crate::registry::{example, Difficulty, Example};
+
+pub const EXAMPLES: &[Example] = &[
+    example!(7, packages_and_crates)
+        .summary("A package with a library and a binary crate, and their roots.")
+        .tags(&["modules"])
+        .difficulty(Difficulty::Beginner)
+        .requires(&["ch3_control_flow::function_call"])
+        .book("ch07-01-packages-and-crates.html"),
+    example!(7, module_paths)
+        .summary("Absolute and relative paths, 'self' and 'super'.")
+        .tags(&["modules"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch7_modules::packages_and_crates"])
+        .shows(&["deliver_order"])
+        .book("ch07-03-paths-for-referring-to-an-item-in-the-module-tree.html"),
+    example!(7, visibility)
+        .summary("Private by default, 'pub', 'pub(crate)', 'pub(super)' and 'pub(in path)'.")
+        .tags(&["modules", "visibility"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch7_modules::module_paths"])
+        .book("ch07-02-defining-modules-to-control-scope-and-privacy.html"),
+    example!(7, struct_visibility)
+        .summary("Public structs with private fields, and public enums.")
+        .tags(&["modules", "visibility", "structs", "enums"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&[
+            "ch7_modules::visibility",
+            "ch5_struct::struct_method",
+            "ch6_enum::enum_syntax",
+        ])
+        .book("ch07-03-paths-for-referring-to-an-item-in-the-module-tree.html#making-structs-and-enums-public"),
+    example!(7, use_declarations)
+        .summary("'use' with nested paths, 'as', globs and 'pub use' re-exports.")
+        .tags(&["modules"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch7_modules::module_paths", "ch8_collections::hashmap_syntax"])
+        .book("ch07-04-bringing-paths-into-scope-with-the-use-keyword.html"),
+    example!(7, module_files)
+        .summary("Modules loaded from separate files and directories.")
+        .tags(&["modules", "files"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch7_modules::use_declarations"])
+        .book("ch07-05-separating-modules-into-different-files.html"),
+];
+
+
+#[cfg(test)]
+mod tests {
+    // 'tests' is a child of 'ch7_modules', so it sees its private items too.
+    use super::*;
+
+    #[test]
+    fn reachable_from_ch7_modules() {
+        assert_eq!(deliver_order("tea"), "tea, delivered"); // private, but an ancestor's
+        assert_eq!(hosting::waitlist_len(), 2); // pub(in crate::ch7_modules)
+        assert_eq!(crate::ch7_modules::hosting::seat_at_table(), 3); // pub(crate)
+    }
+
+    #[test]
+    fn reexport_is_the_same_item() {
+        let direct: fn(&str) -> String = front_of_house::hosting::add_to_waitlist;
+        let reexported: fn(&str) -> String = hosting::add_to_waitlist;
+        assert_eq!(direct("bob"), reexported("bob"));
+    }
+
+    #[test]
+    fn private_fields_through_constructor() {
+        let meal: Breakfast = Breakfast::summer("rye");
+        assert_eq!((meal.toast.as_str(), meal.fruit()), ("rye", "peaches"));
+    }
+}
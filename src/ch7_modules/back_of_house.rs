@@ -0,0 +1,42 @@
+//! The kitchen.
+
+
+/// A public struct with a public and a private field.
+pub struct Breakfast {
+    pub toast: String,
+    seasonal_fruit: String,
+}
+
+
+impl Breakfast {
+    /// As 'seasonal_fruit' is private, other modules can only get a
+    /// Breakfast from a constructor such as this one.
+    pub fn summer(toast: &str) -> Breakfast {
+        Breakfast {
+            toast: String::from(toast),
+            seasonal_fruit: String::from("peaches"),
+        }
+    }
+
+    pub fn fruit(&self) -> &str {
+        &self.seasonal_fruit
+    }
+}
+
+
+/// The variants of a public enum are public too.
+pub enum Appetizer {
+    Soup,
+    Salad,
+}
+
+
+fn cook_order() -> &'static str {
+    "omelette"
+}
+
+
+pub fn fix_incorrect_order() -> String {
+    // 'cook_order' is in this module; 'deliver_order' is in the parent one.
+    super::deliver_order(cook_order())
+}
@@ -0,0 +1,38 @@
+//! The part of the restaurant that customers see.
+
+pub mod hosting;
+mod serving; // private: only 'front_of_house' and its children can use it
+
+
+// private, yet visible to the children 'hosting' and 'serving'.
+fn tables() -> u32 {
+    4
+}
+
+
+/// Serves `dish` through the private 'serving' module, at a table freed by
+/// 'hosting'.
+pub fn serve(dish: &str) -> String {
+    let free: u32 = hosting::free_tables(); // 'pub(super)': visible here
+    format!("{}, served at one of {} free tables", serving::take_order(dish), free)
+}
+
+
+/// The bill, taken by the private 'serving' module.
+pub fn bill() -> u32 {
+    serving::take_payment()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reachable_from_front_of_house() {
+        assert_eq!(tables(), 4);
+        assert_eq!(hosting::free_tables(), 1);
+        assert_eq!(serving::take_order("tea"), "tea");
+        assert_eq!(serving::take_payment(), 12);
+    }
+}
@@ -0,0 +1,33 @@
+//! Seating customers.
+
+
+/// Public, so visible wherever 'front_of_house' is.
+pub fn add_to_waitlist(name: &str) -> String {
+    // 'super::tables' is private to 'front_of_house', an ancestor of this
+    // module, and therefore visible here.
+    format!("{name} is waiting for one of {} tables", super::tables())
+}
+
+
+/// Visible in the whole crate, but not to other crates.
+pub(crate) fn seat_at_table() -> u32 {
+    super::tables() - 1
+}
+
+
+/// Visible in the parent module 'front_of_house' only.
+pub(super) fn free_tables() -> u32 {
+    super::tables() - seat_at_table()
+}
+
+
+/// Visible in 'ch7_modules' and its descendants only.
+pub(in crate::ch7_modules) fn waitlist_len() -> usize {
+    ["bob", "alice"].len()
+}
+
+
+/// The module path and source file of this module.
+pub fn location() -> (&'static str, &'static str) {
+    (module_path!(), file!())
+}
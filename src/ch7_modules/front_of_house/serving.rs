@@ -0,0 +1,13 @@
+//! Taking orders. The module is private to 'front_of_house', so even its 'pub'
+//! items are only reachable from there.
+
+
+pub fn take_order(dish: &str) -> String {
+    dish.to_string()
+}
+
+
+// 'pub(super)' says the same as 'pub' here, since the module is private.
+pub(super) fn take_payment() -> u32 {
+    12
+}
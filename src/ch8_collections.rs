@@ -1,7 +1,30 @@
+//! Chapter 8: the common collections 'Vec', 'String' and 'HashMap'.
+//!
+//! See <https://doc.rust-lang.org/book/ch08-00-common-collections.html>.
+
 use std::vec;
 
 
-// remark: when a vector gets dropped, so does its elements.
+/// remark: when a vector gets dropped, so does its elements.
+///
+/// ```
+/// let mut v: Vec<i32> = vec![1, 2, 3];
+/// for i in &mut v {
+///     *i *= *i;
+/// }
+/// assert_eq!(v, [1, 4, 9]);
+/// assert_eq!(v.get(3), None); // 'v[3]' would panic instead
+/// ```
+///
+/// An element cannot be borrowed while the vector is modified:
+///
+/// ```compile_fail,E0502
+/// let mut v: Vec<i32> = vec![1, 2, 3];
+/// let first: &i32 = &v[0];
+/// v.push(4); // may move the elements to a new allocation
+/// println!("{}", first);
+/// ```
+#[allow(clippy::vec_init_then_push)] // 'vec![1,2]' is shorter
 pub fn vector_syntax() {
 
     // creating vector
@@ -37,7 +60,16 @@ pub fn vector_syntax() {
 }
 
 
-// note that strings can contain any UTF-8 char.
+/// note that strings can contain any UTF-8 char.
+///
+/// ```
+/// let s1: String = String::from("st");
+/// let s2: String = String::from("r");
+/// let s3: String = s1 + &s2; // moves 's1', borrows 's2'
+/// assert_eq!(s3, "str");
+/// assert_eq!(format!("{s3}-{s2}"), "str-r");
+/// assert_eq!("héllo".chars().nth(1), Some('é'));
+/// ```
 pub fn string_syntax() {
     
     // ways to create a string: 
@@ -82,6 +114,35 @@ pub fn string_syntax() {
 
 use std::collections::HashMap;
 
+/// element access
+///
+/// ```
+/// use std::collections::HashMap;
+/// use syntax_examples::ch8_collections::get_price;
+///
+/// let mut price: HashMap<String, i32> = HashMap::new();
+/// price.insert("bread".to_string(), 4);
+/// assert_eq!(get_price(&"bread".to_string(), &price), 4);
+/// assert_eq!(get_price(&"acorn".to_string(), &price), 0);
+/// ```
+#[allow(clippy::ptr_arg)]
+pub fn get_price(name: &String, data: &HashMap<String, i32>) -> i32 {
+    data.get(name) // returns Option<&T> type
+        .copied()
+        .unwrap_or(0)
+}
+
+/// Inserting, overwriting and updating the values of a 'HashMap'.
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// let mut freq: HashMap<char, u32> = HashMap::new();
+/// for c in "hello".chars() {
+///     *freq.entry(c).or_insert(0) += 1;
+/// }
+/// assert_eq!(freq[&'l'], 2);
+/// ```
 pub fn hashmap_syntax() {
 
     // create and ownership transfer
@@ -98,29 +159,22 @@ pub fn hashmap_syntax() {
     // and can no longer be used at this point.
 
 
-    // element access
-
-    fn get_price(name: &String, data: &HashMap<String, i32>) -> i32 {
-        data.get(name) // returns Option<&T> type
-            .copied()
-            .unwrap_or(0)
-    }
-
+    // element access, see 'get_price'
     println!("Bread price is {}", get_price(&"bread".to_string(), &price));
 
 
     // overwrite via inserting again
 
     price.insert("potato".to_string(), 10);
-    assert_eq!(10, price.get(&"potato".to_string()).copied().unwrap_or(0));
+    assert_eq!(10, price.get("potato").copied().unwrap_or(0));
 
     price.insert("potato".to_string(), 15);
-    assert_eq!(15, price.get(&"potato".to_string()).copied().unwrap_or(0));
+    assert_eq!(15, price.get("potato").copied().unwrap_or(0));
 
 
     // insert only if not present
     price.entry("potato".to_string()).or_insert(20);
-    assert_eq!(15, price.get(&"potato".to_string()).copied().unwrap_or(0));
+    assert_eq!(15, price.get("potato").copied().unwrap_or(0));
 
 
     // update existing value
@@ -144,3 +198,64 @@ pub fn hashmap_syntax() {
 }
 
 
+
+
+use crate::registry::{example, Difficulty, Example};
+
+pub const EXAMPLES: &[Example] = &[
+    example!(8, vector_syntax)
+        .summary("Creating, indexing, reading and mutating a Vec.")
+        .tags(&["collections", "vectors"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&[
+            "ch3_control_flow::for_and_while",
+            "ch4_ownership::mutable_references",
+            "ch6_enum::match_syntax",
+        ])
+        .book("ch08-01-vectors.html"),
+    example!(8, string_syntax)
+        .summary("Building, concatenating, formatting and walking a String.")
+        .tags(&["collections", "strings"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch4_ownership::slice", "ch4_ownership::deep_copy"])
+        .book("ch08-02-strings.html"),
+    example!(8, hashmap_syntax)
+        .summary("Inserting, overwriting, 'entry().or_insert' and iterating a HashMap.")
+        .tags(&["collections", "hashmaps", "ownership", "nondeterministic"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&[
+            "ch8_collections::vector_syntax",
+            "ch4_ownership::ownership_through_function",
+        ])
+        .shows(&["get_price"])
+        .book("ch08-03-hash-maps.html"),
+];
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vectors() {
+        vector_syntax();
+    }
+
+    #[test]
+    fn strings() {
+        string_syntax();
+    }
+
+    #[test]
+    fn prices() {
+        let mut price: HashMap<String, i32> = HashMap::new();
+        price.insert("bread".to_string(), 4);
+        assert_eq!(get_price(&"bread".to_string(), &price), 4);
+        assert_eq!(get_price(&"acorn".to_string(), &price), 0); // missing key
+
+        price.entry("bread".to_string()).or_insert(20);
+        assert_eq!(get_price(&"bread".to_string(), &price), 4);
+        *price.entry("bread".to_string()).or_insert(0) += 1;
+        assert_eq!(get_price(&"bread".to_string(), &price), 5);
+    }
+}
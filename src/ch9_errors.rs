@@ -1,12 +1,21 @@
+//! Chapter 9: 'panic!' and 'Result'.
+//!
+//! See <https://doc.rust-lang.org/book/ch09-00-error-handling.html>.
 
-// `panic!` is for unrecoverable errors.
-// actions like accessing out-of-bound array indices will cause 'panic!'.
-//
-// To let `panic!` abort the program and keep the profile of compiled code 
-// small, out may simply put "panic = 'abort' " under "[profile.release]" in 
-// the 'Cargo.toml' file.
-//
-// To enable backtrace, run via `RUST_BACKTRACE=1 cargo run`.
+/// `panic!` is for unrecoverable errors.
+/// actions like accessing out-of-bound array indices will cause 'panic!'.
+///
+/// To let `panic!` abort the program and keep the profile of compiled code 
+/// small, out may simply put "panic = 'abort' " under "[profile.release]" in 
+/// the 'Cargo.toml' file.
+///
+/// To enable backtrace, run via `RUST_BACKTRACE=1 cargo run`.
+///
+/// ```should_panic
+/// let v: Vec<i32> = vec![1, 2, 3];
+/// let index: usize = v.len();
+/// v[index]; // panics: index out of bounds
+/// ```
 pub fn panic_syntax() {
     let a: i32 = 1;
     if a == 0 {
@@ -18,9 +27,19 @@ pub fn panic_syntax() {
 use std::fs::File;
 use std::io::{Error, ErrorKind, Read};
 
-// Suppose we want to open a file; if an error is encountered because file does 
-// not exist, we shall create a new file; if we have encountered an error 
-// otherwise, we shall panic. 
+/// Suppose we want to open a file; if an error is encountered because file does 
+/// not exist, we shall create a new file; if we have encountered an error 
+/// otherwise, we shall panic. 
+///
+/// ```
+/// use std::fs::File;
+/// use std::io::ErrorKind;
+///
+/// match File::open("surely/missing/file.txt") {
+///     Ok(_) => panic!("the file should not exist"),
+///     Err(e) => assert_eq!(e.kind(), ErrorKind::NotFound),
+/// }
+/// ```
 pub fn match_on_error() {
 
     // standard but tedious way
@@ -44,6 +63,17 @@ pub fn match_on_error() {
 }
 
 
+/// 'unwrap' and 'expect' take the value out of an 'Ok', and panic on an 'Err'.
+///
+/// ```
+/// let n: i32 = "233".parse::<i32>().unwrap();
+/// assert_eq!(n, 233);
+/// assert_eq!("x".parse::<i32>().unwrap_or(0), 0);
+/// ```
+///
+/// ```should_panic
+/// let _n: i32 = "x".parse::<i32>().expect("not a number");
+/// ```
 pub fn unwrap_expect() {
     // x.unwrap() equals y x matches to Ok(y), else panic.
     let _f: File = File::open("temp.txt").unwrap();
@@ -60,8 +90,13 @@ pub fn unwrap_expect() {
 }
 
 
-// and equivalent but shorter way for match_on_error; requires lambda 
-// (which will be explained in future chapters) 
+/// and equivalent but shorter way for match_on_error; requires a closure
+/// (see 'ch13_closures').
+///
+/// ```
+/// let n: i32 = "x".parse::<i32>().unwrap_or_else(|_| -1);
+/// assert_eq!(n, -1);
+/// ```
 pub fn short_match() {
     let _f: File = File::open("temp.txt").unwrap_or_else(|e| {
         if e.kind() == ErrorKind::NotFound {
@@ -73,15 +108,47 @@ pub fn short_match() {
 }
 
 
-// remark: we can write a function that returns Result<T, E> if its execution 
-// can potentially lead to error; in this case, whatever it returns is being 
-// propagated to the caller. To make it simple, we use the '?' operator. 
+/// the '?' operator can also be used in function of return type `Option<T>`.
+///
+/// ```
+/// use syntax_examples::ch9_errors::last_chr_of_fst_line;
+///
+/// assert_eq!(last_chr_of_fst_line("bob likes alice\nalice likes rust"), Some('e'));
+/// assert_eq!(last_chr_of_fst_line(""), None);
+/// ```
+pub fn last_chr_of_fst_line(text: &str) -> Option<char> {
+    text.lines().next()?.chars().last()
+}
+
+
+/// remark: we can write a function that returns `Result<T, E>` if its execution 
+/// can potentially lead to error; in this case, whatever it returns is being 
+/// propagated to the caller. To make it simple, we use the '?' operator. 
+///
+/// ```
+/// use std::num::ParseIntError;
+///
+/// fn sum(a: &str, b: &str) -> Result<i32, ParseIntError> {
+///     Ok(a.parse::<i32>()? + b.parse::<i32>()?)
+/// }
+///
+/// assert_eq!(sum("1", "2"), Ok(3));
+/// assert!(sum("1", "two").is_err());
+/// ```
+///
+/// '?' only works in functions that return 'Result' or 'Option':
+///
+/// ```compile_fail,E0277
+/// fn parse(s: &str) -> i32 {
+///     s.parse::<i32>()?
+/// }
+/// ```
 pub fn err_propagation() {
 
     // if an error happens to occur at any one of the two question-marks, 
     // such an error will be propagated back to the caller, in the form of 
     // returning some Err(_). No need for explicit match-casing, yay!
-    fn _get_str_from_file() -> Result<String, Error> {
+    fn get_str_from_file() -> Result<String, Error> {
         let mut f: File = File::open("temp.txt")?;
         let mut s = String::new();
         f.read_to_string(&mut s)?;
@@ -91,9 +158,9 @@ pub fn err_propagation() {
     // an even shorter implementation.
     //
     // Note that this also works for 'main' function: you just have to change 
-    // its return type to Result<(), Box<dyn Error>>. This type of Box<T> stuff 
-    // will be covered in future chapters.
-    fn _shorter_get_str_from_file() -> Result<String, Error> {
+    // its return type to Result<(), Box<dyn Error>>. Box<T> is covered in
+    // 'ch15_smart_pointers'.
+    fn shorter_get_str_from_file() -> Result<String, Error> {
         let mut s = String::new();
         File::open("temp.txt")?.read_to_string(&mut s)?;
         Ok(s)
@@ -103,8 +170,137 @@ pub fn err_propagation() {
     // like `std::fs::read_to_string("temp.txt");`.
 
 
-    // the '?' operator can also be used in function of return type Option<T>.
-    fn _last_chr_of_fst_line(text: &str) -> Option<char> {
-        return text.lines().next()?.chars().last();
+    // this example runs with "bob likes alice\nalice likes rust\n" in temp.txt
+    let text: String = get_str_from_file().unwrap();
+    assert_eq!(text, shorter_get_str_from_file().unwrap());
+    assert_eq!(text, std::fs::read_to_string("temp.txt").unwrap());
+
+    assert_eq!(last_chr_of_fst_line(&text), Some('e'));
+    assert_eq!(last_chr_of_fst_line(""), None); // returned at the first '?'
+}
+
+
+use crate::registry::{example, Difficulty, Example};
+use crate::sandbox::Setup;
+
+// the examples above open "temp.txt" in the current directory; each one runs
+// in its own sandbox, either with or without that file.
+const TEMP_FILE_PRESENT: Setup = Setup::FilePresent {
+    path: "temp.txt",
+    contents: "bob likes alice\nalice likes rust\n",
+};
+const TEMP_FILE_ABSENT: Setup = Setup::FileAbsent { path: "temp.txt" };
+
+pub const EXAMPLES: &[Example] = &[
+    example!(9, panic_syntax)
+        .summary("'panic!' for unrecoverable errors.")
+        .tags(&["error-handling", "panic"])
+        .difficulty(Difficulty::Beginner)
+        .requires(&["ch3_control_flow::if_syntax"])
+        .book("ch09-01-unrecoverable-errors-with-panic.html"),
+    example!(9, match_on_error)
+        .summary("Matching on a Result and on the kind of its error.")
+        .tags(&["error-handling", "result", "pattern-matching", "files"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch9_errors::panic_syntax", "ch6_enum::match_syntax"])
+        .book("ch09-02-recoverable-errors-with-result.html#matching-on-different-errors")
+        .setup(TEMP_FILE_ABSENT),
+    example!(9, unwrap_expect)
+        .summary("'unwrap' and 'expect' panic on Err.")
+        .tags(&["error-handling", "result", "panic", "files"])
+        .difficulty(Difficulty::Intermediate)
+        .requires(&["ch9_errors::match_on_error"])
+        .book("ch09-02-recoverable-errors-with-result.html#shortcuts-for-panic-on-error-unwrap-and-expect")
+        .setup(TEMP_FILE_PRESENT),
+    example!(9, short_match)
+        .summary("'unwrap_or_else' with a closure instead of nested matches.")
+        .tags(&["error-handling", "result", "closures", "files"])
+        .difficulty(Difficulty::Advanced)
+        .requires(&["ch9_errors::unwrap_expect"])
+        .book("ch09-02-recoverable-errors-with-result.html#alternatives-to-using-match-with-resultt-e")
+        .setup(TEMP_FILE_ABSENT),
+    example!(9, err_propagation)
+        .summary("Propagating errors to the caller with the '?' operator.")
+        .tags(&["error-handling", "result", "option", "files"])
+        .difficulty(Difficulty::Advanced)
+        .requires(&["ch9_errors::match_on_error", "ch3_control_flow::function_call"])
+        .shows(&["last_chr_of_fst_line"])
+        .book("ch09-02-recoverable-errors-with-result.html#propagating-errors")
+        .setup(TEMP_FILE_PRESENT),
+];
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sandbox::Sandbox;
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn panic_syntax_does_not_panic() {
+        panic_syntax();
+    }
+
+    #[test]
+    fn match_on_error_creates_missing_file() {
+        let _sandbox = Sandbox::enter(TEMP_FILE_ABSENT).unwrap();
+        match_on_error();
+        assert_eq!(fs::read_to_string("temp.txt").unwrap(), "");
+    }
+
+    #[test]
+    fn match_on_error_opens_existing_file() {
+        let _sandbox = Sandbox::enter(TEMP_FILE_PRESENT).unwrap();
+        match_on_error();
+        assert_eq!(fs::read_to_string("temp.txt").unwrap(), "bob likes alice\nalice likes rust\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "called `Result::unwrap()` on an `Err` value")]
+    fn unwrap_expect_panics_without_file() {
+        let _sandbox = Sandbox::enter(TEMP_FILE_ABSENT).unwrap();
+        unwrap_expect();
+    }
+
+    #[test]
+    fn unwrap_expect_opens_existing_file() {
+        let _sandbox = Sandbox::enter(TEMP_FILE_PRESENT).unwrap();
+        unwrap_expect();
+    }
+
+    #[test]
+    fn short_match_creates_missing_file() {
+        let _sandbox = Sandbox::enter(TEMP_FILE_ABSENT).unwrap();
+        short_match();
+        assert!(Path::new("temp.txt").exists());
+    }
+
+    #[test]
+    #[should_panic(expected = "called `Result::unwrap()` on an `Err` value")]
+    fn err_propagation_without_file() {
+        let _sandbox = Sandbox::enter(TEMP_FILE_ABSENT).unwrap();
+        err_propagation();
+    }
+
+    #[test]
+    fn short_match_opens_existing_file() {
+        let _sandbox = Sandbox::enter(TEMP_FILE_PRESENT).unwrap();
+        short_match();
+        assert_eq!(fs::read_to_string("temp.txt").unwrap(), "bob likes alice\nalice likes rust\n");
+    }
+
+    #[test]
+    fn err_propagation_reads_file() {
+        let _sandbox = Sandbox::enter(TEMP_FILE_PRESENT).unwrap();
+        err_propagation();
+    }
+
+    #[test]
+    fn last_char_of_first_line() {
+        assert_eq!(last_chr_of_fst_line("bob likes alice\nalice likes rust"), Some('e'));
+        assert_eq!(last_chr_of_fst_line("rust"), Some('t')); // a single line
+        assert_eq!(last_chr_of_fst_line(""), None); // no line at all
+        assert_eq!(last_chr_of_fst_line("\nrust"), None); // an empty first line
     }
 }
@@ -0,0 +1,773 @@
+//! Command-line interface over the example registry.
+
+use std::io::{self, IsTerminal};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use syntax_examples::book::{self, ChapterCoverage, Status};
+use syntax_examples::capture;
+use syntax_examples::compile_fail::{self, Case};
+use syntax_examples::error_codes;
+use syntax_examples::golden::{self, Verdict};
+use syntax_examples::path;
+use syntax_examples::profile;
+use syntax_examples::quiz::{self, Question};
+use syntax_examples::registry::{self, Difficulty, Example};
+use syntax_examples::report::{self, Format};
+use syntax_examples::runner::{self, Outcome, Report, Totals};
+use syntax_examples::search::{self, Hit};
+use syntax_examples::select::{self, Selection};
+use syntax_examples::source;
+
+
+pub const USAGE: &str = "\
+usage: syntax_examples [COMMAND] [OPTIONS] [PATTERN...]
+
+commands:
+    run      run the selected examples (default)
+    list     list the selected examples
+    path     print a reading order of the selected examples, where each
+             one comes after the examples it requires
+    show     print the source and commentary of the selected examples
+    search   find examples whose comments or code mention every word of
+             a query, eg. `search entry().or_insert` or `search shadowing`
+    quiz     answer questions about the selected examples
+    compile-fail
+             check with rustc that the code the selected examples describe
+             as rejected indeed fails to compile, with the right error code
+    explain [CODE]
+             explain a rustc error code, eg. `explain E0502`, with the
+             examples and failing code showing it; without a code, list
+             the known codes
+    coverage list the sections of The Book that the examples cover,
+             partly cover (with placeholder examples only) or miss, with
+             the share covered in each chapter
+    golden   compare what the selected examples print with golden/
+    help     print this message
+
+options:
+    -c, --chapter N    select examples of chapter N
+    -t, --tag TAG      select examples tagged TAG
+    -d, --difficulty D select beginner, intermediate or advanced examples
+    -l, --long         (list) also print tags, difficulty, prerequisites and
+                       the link to The Book
+    -s, --skip PATTERN (run) report matching examples as skipped
+    -x, --fail-fast    (run) skip the remaining examples after a failure
+    -f, --format FMT   (run) print a report as text (default), json or junit;
+                       json and junit capture what each example prints
+    -n, --repeat N     (run) run each example N times and report mean times
+    -p, --profile      (run) print the slowest examples and their share of
+                       the total time; text format only
+    --bless            (golden) overwrite the golden files with the output
+
+patterns:
+    ch4_ownership::slice    a single example
+    'ch8::*map*'            wildcards, with the module abbreviated to its chapter
+    '*slice*'               a pattern without '::' matches example names only
+";
+
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run { selection: Selection, options: RunOptions },
+    List { selection: Selection, long: bool },
+    Path(Selection),
+    Show(Selection),
+    Search(String),
+    Quiz(Selection),
+    CompileFail(Selection),
+    Explain(Option<String>),
+    Coverage,
+    Golden { selection: Selection, bless: bool },
+    /// Runs a single example for `capture::run_captured`.
+    Exec(String),
+    Help,
+}
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RunOptions {
+    /// Patterns of examples to report as skipped.
+    pub skip: Vec<String>,
+    pub fail_fast: bool,
+    pub format: Format,
+    /// How many times to run each example.
+    pub repeat: u32,
+    pub profile: bool,
+}
+
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        RunOptions {
+            skip: Vec::new(),
+            fail_fast: false,
+            format: Format::Text,
+            repeat: 1,
+            profile: false,
+        }
+    }
+}
+
+
+/// Parses the arguments that follow the program name.
+pub fn parse(args: &[String]) -> Result<Command, String> {
+    let (command, rest) = match args.first().map(String::as_str) {
+        Some("help" | "-h" | "--help") => return Ok(Command::Help),
+        Some("run") => ("run", &args[1..]),
+        Some("list") => ("list", &args[1..]),
+        Some("golden") => ("golden", &args[1..]),
+        Some("path") => ("path", &args[1..]),
+        Some("show") => ("show", &args[1..]),
+        Some("quiz") => ("quiz", &args[1..]),
+        Some("compile-fail") => ("compile-fail", &args[1..]),
+        Some("explain") => {
+            return match &args[1..] {
+                [] => Ok(Command::Explain(None)),
+                [code] => Ok(Command::Explain(Some(code.clone()))),
+                _ => Err("explain expects a single error code".to_string()),
+            };
+        },
+        Some("coverage") => {
+            return match &args[1..] {
+                [] => Ok(Command::Coverage),
+                _ => Err("coverage takes no arguments".to_string()),
+            };
+        },
+        Some("search") => {
+            return match &args[1..] {
+                [] => Err("search expects a query".to_string()),
+                query => Ok(Command::Search(query.join(" "))),
+            };
+        },
+        Some(capture::EXEC_COMMAND) => {
+            return match &args[1..] {
+                [id] => Ok(Command::Exec(id.clone())),
+                _ => Err(format!("{} expects an example id", capture::EXEC_COMMAND)),
+            };
+        },
+        _ => ("run", args), // `cargo run` alone runs everything
+    };
+
+    let mut selection = Selection::default();
+    let mut long: bool = false;
+    let mut options = RunOptions::default();
+    let mut bless: bool = false;
+
+    let mut iter = rest.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-c" | "--chapter" => {
+                let value: &String = iter.next()
+                    .ok_or(format!("{arg} expects a chapter number"))?;
+                let chapter: u32 = value.parse()
+                    .map_err(|_| format!("invalid chapter number '{value}'"))?;
+                selection.chapters.push(chapter);
+            },
+            "-t" | "--tag" => {
+                let value: &String = iter.next()
+                    .ok_or(format!("{arg} expects a tag"))?;
+                selection.tags.push(value.clone());
+            },
+            "-d" | "--difficulty" => {
+                let value: &String = iter.next()
+                    .ok_or(format!("{arg} expects beginner, intermediate or advanced"))?;
+                let difficulty: Difficulty = Difficulty::parse(value)
+                    .ok_or(format!("unknown difficulty '{value}'"))?;
+                selection.difficulties.push(difficulty);
+            },
+            "-l" | "--long" if command == "list" => long = true,
+            "-s" | "--skip" if command == "run" => {
+                let value: &String = iter.next()
+                    .ok_or(format!("{arg} expects a pattern"))?;
+                options.skip.push(value.clone());
+            },
+            "-x" | "--fail-fast" if command == "run" => options.fail_fast = true,
+            "-f" | "--format" if command == "run" => {
+                let value: &String = iter.next()
+                    .ok_or(format!("{arg} expects text, json or junit"))?;
+                options.format = Format::parse(value)
+                    .ok_or(format!("unknown format '{value}'"))?;
+            },
+            "-n" | "--repeat" if command == "run" => {
+                let value: &String = iter.next()
+                    .ok_or(format!("{arg} expects a number of runs"))?;
+                options.repeat = value.parse().ok().filter(|n| *n > 0)
+                    .ok_or(format!("invalid number of runs '{value}'"))?;
+            },
+            "-p" | "--profile" if command == "run" => options.profile = true,
+            "--bless" if command == "golden" => bless = true,
+            other if other.starts_with('-') => {
+                return Err(format!("unknown option '{other}'"));
+            },
+            pattern => selection.patterns.push(pattern.to_string()),
+        }
+    }
+
+    if options.profile && options.format != Format::Text {
+        return Err(String::from(
+            "--profile only applies to --format text; json reports include each \
+             example's 'stddev_ms' and 'share'"
+        ));
+    }
+
+    Ok(match command {
+        "list" => Command::List { selection, long },
+        "golden" => Command::Golden { selection, bless },
+        "path" => Command::Path(selection),
+        "show" => Command::Show(selection),
+        "quiz" => Command::Quiz(selection),
+        "compile-fail" => Command::CompileFail(selection),
+        _ => Command::Run { selection, options },
+    })
+}
+
+
+pub fn execute(command: Command) -> ExitCode {
+    match command {
+        Command::Help => {
+            print!("{USAGE}");
+            ExitCode::SUCCESS
+        },
+        Command::List { selection, long } => list(&selection, long),
+        Command::Path(selection) => print_path(&selection),
+        Command::Show(selection) => show(&selection),
+        Command::Search(query) => find(&query),
+        Command::Quiz(selection) => ask(&selection),
+        Command::CompileFail(selection) => check_compile_fail(&selection),
+        Command::Explain(code) => explain(code.as_deref()),
+        Command::Coverage => print_coverage(),
+        Command::Run { selection, options } => run(&selection, &options),
+        Command::Golden { selection, bless } => check_golden(&selection, bless),
+        Command::Exec(id) => capture::exec(&id),
+    }
+}
+
+
+fn list(selection: &Selection, long: bool) -> ExitCode {
+    for chapter in registry::CHAPTERS {
+        let examples: Vec<&Example> = chapter.examples.iter()
+            .filter(|example| selection.matches(example))
+            .collect();
+
+        if examples.is_empty() {
+            continue;
+        }
+
+        println!("Chapter {}: {}", chapter.number, chapter.title);
+        for example in examples {
+            println!("  {:<40} {}", example.id(), example.summary);
+            if long {
+                println!("  {:<40} tags: {}", "", example.tags.join(", "));
+                println!("  {:<40} difficulty: {}", "", example.difficulty.name());
+                if !example.requires.is_empty() {
+                    println!("  {:<40} requires: {}", "", example.requires.join(", "));
+                }
+                println!("  {:<40} {}", "", example.book_url());
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+
+fn print_path(selection: &Selection) -> ExitCode {
+    let examples: Vec<&'static Example> = selection.examples();
+
+    if examples.is_empty() {
+        eprintln!("error: no example matches the selection");
+        return ExitCode::from(2);
+    }
+
+    let problems: Vec<path::Problem> = path::validate(&registry::all().collect::<Vec<_>>());
+    if !problems.is_empty() {
+        for problem in problems {
+            eprintln!("error: {problem}");
+        }
+        return ExitCode::FAILURE;
+    }
+
+    let order: Vec<&'static Example> = match path::reading_order(&examples) {
+        Ok(order) => order,
+        Err(problem) => {
+            eprintln!("error: {problem}");
+            return ExitCode::FAILURE;
+        },
+    };
+    for (i, example) in order.iter().enumerate() {
+        let pulled_in: &str = if selection.matches(example) { "" } else { " (prerequisite)" };
+        println!(
+            "{:>3}. [{:<12}] {}{}",
+            i + 1, example.difficulty.name(), example.id(), pulled_in,
+        );
+        println!("     {}", example.summary);
+    }
+    ExitCode::SUCCESS
+}
+
+
+fn show(selection: &Selection) -> ExitCode {
+    let examples: Vec<&'static Example> = selection.examples();
+
+    if examples.is_empty() || selection == &Selection::default() {
+        eprintln!("error: show expects an example, eg. `show ch4_ownership::slice`");
+        return ExitCode::from(2);
+    }
+
+    for (i, example) in examples.iter().enumerate() {
+        let Some(snippet) = source::snippet_of(example) else {
+            eprintln!("error: cannot find the source of {}", example.id());
+            return ExitCode::FAILURE;
+        };
+
+        if i > 0 {
+            println!();
+        }
+        println!("{} ({}:{})", example.id(), source::path_of(example), snippet.line);
+        println!("{}", example.summary);
+        println!("{}", example.book_url());
+        println!();
+        print!("{}", snippet.text());
+    }
+    ExitCode::SUCCESS
+}
+
+
+/// How many matching lines `search` prints per example.
+const SEARCH_CONTEXT: usize = 3;
+
+fn find(query: &str) -> ExitCode {
+    let hits: Vec<Hit> = search::search(query);
+    if hits.is_empty() {
+        println!("no example mentions '{query}'");
+        return ExitCode::FAILURE;
+    }
+
+    // bold when printing to a terminal
+    let (open, close) = if io::stdout().is_terminal() {
+        ("\x1b[1m", "\x1b[0m")
+    } else {
+        ("", "")
+    };
+
+    for hit in &hits {
+        println!("{}  (score {})", hit.example.id(), hit.score);
+        if hit.lines.is_empty() {
+            println!("  {}", hit.example.summary); // matched its name or tags
+        }
+        let path: String = source::path_of(hit.example);
+        for line in hit.lines.iter().take(SEARCH_CONTEXT) {
+            let text: String = search::highlight(line, open, close);
+            println!("  {}:{}: {}", path, line.number, text.trim());
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+
+fn ask(selection: &Selection) -> ExitCode {
+    let questions: Vec<Question> = quiz::questions().into_iter()
+        .filter(|q| q.example().is_some_and(|example| selection.matches(example)))
+        .collect();
+
+    if questions.is_empty() {
+        eprintln!("error: there is no question about the selected examples");
+        return ExitCode::from(2);
+    }
+
+    match quiz::ask(&questions, &mut io::stdin().lock(), &mut io::stdout()) {
+        Ok(_) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        },
+    }
+}
+
+
+fn check_compile_fail(selection: &Selection) -> ExitCode {
+    let cases: Vec<&Case> = compile_fail::CASES.iter()
+        .filter(|case| {
+            registry::find(case.example).is_some_and(|example| selection.matches(example))
+        })
+        .collect();
+
+    if cases.is_empty() {
+        eprintln!("error: the selected examples have no compile-fail cases");
+        return ExitCode::from(2);
+    }
+
+    let mut failed: usize = 0;
+    for case in &cases {
+        let path: String = case.path();
+        match compile_fail::check(case) {
+            Ok(compile_fail::Verdict::Rejected) => {
+                println!("  ok        {path} ({})", case.error);
+            },
+            Ok(compile_fail::Verdict::Compiled) => {
+                failed += 1;
+                println!("  COMPILED  {path}: expected error {}", case.error);
+            },
+            Ok(compile_fail::Verdict::WrongErrors { codes, stderr }) => {
+                failed += 1;
+                println!(
+                    "  WRONG     {path}: expected error {}, got [{}]",
+                    case.error, codes.join(", "),
+                );
+                for line in stderr.lines() {
+                    println!("    {line}");
+                }
+            },
+            Err(e) => {
+                eprintln!("error: cannot run rustc: {e}");
+                return ExitCode::from(2);
+            },
+        }
+    }
+
+    println!("{} cases checked, {} failed", cases.len(), failed);
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+
+fn explain(code: Option<&str>) -> ExitCode {
+    let Some(code) = code else {
+        for error in error_codes::ERROR_CODES {
+            println!("  {}  {}", error.code, error.title);
+        }
+        return ExitCode::SUCCESS;
+    };
+
+    let Some(error) = error_codes::find(code) else {
+        eprintln!("error: no explanation for '{code}'; run `explain` to list the known codes");
+        return ExitCode::from(2);
+    };
+    let indent = |text: &str| {
+        for line in text.lines() {
+            println!("{}", format!("    {line}").trim_end());
+        }
+    };
+
+    println!("{}: {}", error.code, error.title);
+    println!("{}", error.url());
+    println!();
+    println!("{}", error.description);
+
+    for case in error.cases() {
+        println!();
+        println!("Failing code ({}):", case.path());
+        indent(case.code);
+    }
+
+    println!();
+    println!("Minimal fix:");
+    indent(error.fix);
+
+    for example in error.examples() {
+        println!();
+        match source::snippet_of(example) {
+            Some(snippet) => {
+                println!("See {} ({}:{}):", example.id(), source::path_of(example), snippet.line);
+                indent(&snippet.text());
+            },
+            None => println!("See {}", example.id()),
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+
+fn print_coverage() -> ExitCode {
+    let chapters: Vec<ChapterCoverage> = book::coverage();
+
+    for coverage in &chapters {
+        println!(
+            "Chapter {}: {} ({:.0}%)",
+            coverage.chapter.number, coverage.chapter.title, coverage.percent(),
+        );
+        for section in &coverage.sections {
+            println!("  {:<9} {}", section.status.name(), section.section.title);
+            if section.status != Status::Missing {
+                let ids: Vec<String> = section.examples.iter().map(|e| e.id()).collect();
+                println!("  {:<9} {}", "", ids.join(", "));
+            }
+        }
+    }
+
+    let count = |status: Status| -> usize {
+        chapters.iter()
+            .flat_map(|chapter| &chapter.sections)
+            .filter(|section| section.status == status)
+            .count()
+    };
+    println!();
+    println!(
+        "{:.0}% of The Book: {} sections covered, {} partial, {} missing",
+        book::total_percent(&chapters),
+        count(Status::Covered), count(Status::Partial), count(Status::Missing),
+    );
+    ExitCode::SUCCESS
+}
+
+
+fn run(selection: &Selection, options: &RunOptions) -> ExitCode {
+    let examples: Vec<&'static Example> = selection.examples();
+
+    if examples.is_empty() {
+        eprintln!("error: no example matches the selection");
+        return ExitCode::from(2);
+    }
+
+    let mut reports: Vec<Report> = Vec::new();
+    let mut failed: bool = false;
+
+    for example in examples {
+        let report: Report = if failed && options.fail_fast {
+            skipped(example, "an earlier example failed")
+        } else if options.skip.iter().any(|p| select::matches_pattern(p, example)) {
+            skipped(example, "--skip")
+        } else {
+            if options.format == Format::Text {
+                println!("--- {}", example.id());
+            }
+            match timed_runs(example, options) {
+                Ok(report) => report,
+                Err(e) => {
+                    eprintln!("error: cannot run {}: {e}", example.id());
+                    return ExitCode::from(2);
+                },
+            }
+        };
+
+        failed |= matches!(report.outcome, Outcome::Failed(_));
+        reports.push(report);
+    }
+
+    match options.format {
+        Format::Text => {
+            runner::print_summary(&reports);
+            if options.profile {
+                profile::print_profile(&reports, PROFILE_TOP);
+            }
+        },
+        Format::Json => print!("{}", report::to_json(&reports)),
+        Format::JUnit => print!("{}", report::to_junit(&reports)),
+    }
+
+    if Totals::of(&reports).failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+
+/// How many examples `--profile` lists.
+const PROFILE_TOP: usize = 10;
+
+
+/// Runs `example` `options.repeat` times, or until it fails. Text reports
+/// run it in this process; the others capture its output in a child process,
+/// since the report itself goes to stdout, and use the time the child
+/// measured so that starting the process is not counted.
+fn timed_runs(example: &'static Example, options: &RunOptions) -> io::Result<Report> {
+    let mut samples: Vec<Duration> = Vec::new();
+    let mut outcome: Outcome = Outcome::Passed;
+    let mut stdout: Option<String> = None;
+
+    for _ in 0..options.repeat {
+        if options.format == Format::Text {
+            let start = Instant::now();
+            outcome = runner::run_one(example);
+            samples.push(start.elapsed());
+        } else {
+            let captured = capture::run_captured(example)?;
+            outcome = captured.outcome;
+            stdout = Some(captured.stdout);
+            samples.push(captured.elapsed.unwrap_or_default());
+        }
+
+        if outcome != Outcome::Passed {
+            break;
+        }
+    }
+
+    Ok(Report { example, outcome, duration: profile::mean(&samples), samples, stdout })
+}
+
+
+fn skipped(example: &'static Example, reason: &str) -> Report {
+    Report {
+        example,
+        outcome: Outcome::Skipped(reason.to_string()),
+        duration: Duration::ZERO,
+        samples: Vec::new(),
+        stdout: None,
+    }
+}
+
+
+fn check_golden(selection: &Selection, bless: bool) -> ExitCode {
+    let examples: Vec<&'static Example> = selection.examples();
+
+    if examples.is_empty() {
+        eprintln!("error: no example matches the selection");
+        return ExitCode::from(2);
+    }
+
+    let mut failed: usize = 0;
+
+    for example in &examples {
+        let id: String = example.id();
+        let verdict: Verdict = match golden::check(example, bless) {
+            Ok(verdict) => verdict,
+            Err(e) => {
+                eprintln!("error: {id}: {e}");
+                return ExitCode::from(2);
+            },
+        };
+
+        match verdict {
+            Verdict::Matched => println!("  ok        {id}"),
+            Verdict::Blessed => println!("  blessed   {id}"),
+            Verdict::Skipped(reason) => println!("  skipped   {id} ({reason})"),
+            Verdict::Mismatched { diff } => {
+                failed += 1;
+                println!("  MISMATCH  {id}");
+                println!("    --- {}", golden::path_of(example).display());
+                println!("    +++ actual output");
+                for line in diff.lines() {
+                    println!("    {line}");
+                }
+            },
+            Verdict::Failed(outcome) => {
+                failed += 1;
+                println!("  FAIL      {id}");
+                if let Outcome::Failed(failure) = outcome {
+                    for line in failure.message.lines() {
+                        println!("    {line}");
+                    }
+                }
+            },
+        }
+    }
+
+    println!("{} examples checked, {} failed", examples.len(), failed);
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parse_commands() {
+        assert_eq!(parse(&[]), Ok(Command::Run {
+            selection: Selection::default(), options: RunOptions::default(),
+        }));
+        assert_eq!(parse(&args("help")), Ok(Command::Help));
+        assert_eq!(
+            parse(&args("golden --bless -c 4")),
+            Ok(Command::Golden {
+                selection: Selection { chapters: vec![4], ..Selection::default() },
+                bless: true,
+            }),
+        );
+        assert_eq!(
+            parse(&args("show ch4::slice")),
+            Ok(Command::Show(Selection {
+                patterns: vec!["ch4::slice".to_string()],
+                ..Selection::default()
+            })),
+        );
+        assert_eq!(
+            parse(&args("quiz -c 4")),
+            Ok(Command::Quiz(Selection { chapters: vec![4], ..Selection::default() })),
+        );
+        assert_eq!(
+            parse(&args("path -d advanced -d beginner")),
+            Ok(Command::Path(Selection {
+                difficulties: vec![Difficulty::Advanced, Difficulty::Beginner],
+                ..Selection::default()
+            })),
+        );
+        assert_eq!(parse(&args("coverage")), Ok(Command::Coverage));
+        assert_eq!(parse(&args("explain")), Ok(Command::Explain(None)));
+        assert_eq!(
+            parse(&args("explain e0502")),
+            Ok(Command::Explain(Some("e0502".to_string()))),
+        );
+        assert_eq!(
+            parse(&args("search mutable --slice")),
+            Ok(Command::Search("mutable --slice".to_string())),
+        );
+        assert_eq!(
+            parse(&args("__exec ch4_ownership::slice")),
+            Ok(Command::Exec("ch4_ownership::slice".to_string())),
+        );
+
+        let selection = Selection {
+            patterns: vec!["ch8::*map*".to_string()],
+            chapters: vec![9],
+            tags: vec!["ownership".to_string()],
+            difficulties: vec![],
+        };
+        assert_eq!(
+            parse(&args("run --chapter 9 ch8::*map* -t ownership -x -s *slice* -f junit")),
+            Ok(Command::Run {
+                selection: selection.clone(),
+                options: RunOptions {
+                    skip: vec!["*slice*".to_string()],
+                    fail_fast: true,
+                    format: Format::JUnit,
+                    ..RunOptions::default()
+                },
+            }),
+        );
+        assert_eq!(
+            parse(&args("run --profile -n 5")),
+            Ok(Command::Run {
+                selection: Selection::default(),
+                options: RunOptions { repeat: 5, profile: true, ..RunOptions::default() },
+            }),
+        );
+        assert_eq!(
+            parse(&args("list -c 9 -l ch8::*map* --tag ownership")),
+            Ok(Command::List { selection, long: true }),
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(parse(&args("run --chapter")).is_err());
+        assert!(parse(&args("run --chapter four")).is_err());
+        assert!(parse(&args("run --long")).is_err());
+        assert!(parse(&args("list --fail-fast")).is_err());
+        assert!(parse(&args("run --skip")).is_err());
+        assert!(parse(&args("list --frobnicate")).is_err());
+        assert!(parse(&args("run --bless")).is_err());
+        assert!(parse(&args("run --format yaml")).is_err());
+        assert!(parse(&args("path --difficulty expert")).is_err());
+        assert!(parse(&args("run --repeat 0")).is_err());
+        assert!(parse(&args("list --profile")).is_err());
+        assert!(parse(&args("run --profile -f json")).is_err());
+        assert!(parse(&args("run -f junit -p")).is_err());
+        assert!(parse(&args("__exec")).is_err());
+        assert!(parse(&args("search")).is_err());
+        assert!(parse(&args("explain E0502 E0499")).is_err());
+        assert!(parse(&args("coverage -c 4")).is_err());
+    }
+}
@@ -0,0 +1,213 @@
+//! Code that must NOT compile, checked against the local `rustc`.
+//!
+//! Several examples can only describe in comments what the compiler rejects.
+//! The rejected code itself lives in `src/compile_fail/<chapter>/<name>.rs`,
+//! one small program per file (they are not modules of this crate). Each case
+//! below records which example it belongs to and which error code `rustc`
+//! must report for it.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::process::{self, Command};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+
+#[derive(Clone, Copy, Debug)]
+pub struct Case {
+    /// Id of the example whose comments describe this code.
+    pub example: &'static str,
+    pub name: &'static str,
+    /// The expected error code, eg. "E0382".
+    pub error: &'static str,
+    pub code: &'static str,
+}
+
+
+macro_rules! case {
+    ($example:literal, $module:literal, $name:literal, $error:literal) => {
+        Case {
+            example: $example,
+            name: $name,
+            error: $error,
+            code: include_str!(concat!("compile_fail/", $module, "/", $name, ".rs")),
+        }
+    };
+}
+
+
+pub const CASES: &[Case] = &[
+    case!("ch1_basics::variable",
+          "ch1_basics", "reassign_immutable", "E0384"),
+    case!("ch4_ownership::shallow_transfer",
+          "ch4_ownership", "use_after_move", "E0382"),
+    case!("ch4_ownership::switch_between_references",
+          "ch4_ownership", "two_mutable_borrows", "E0499"),
+    case!("ch4_ownership::slice_ownership",
+          "ch4_ownership", "clear_while_borrowed", "E0502"),
+    case!("ch4_ownership::immutable_references",
+          "ch4_ownership", "move_while_borrowed", "E0505"),
+    case!("ch7_modules::visibility",
+          "ch7_modules", "private_module", "E0603"),
+    case!("ch7_modules::visibility",
+          "ch7_modules", "restricted_visibility", "E0603"),
+    case!("ch7_modules::struct_visibility",
+          "ch7_modules", "private_field", "E0616"),
+    case!("ch7_modules::struct_visibility",
+          "ch7_modules", "private_field_in_literal", "E0451"),
+    case!("ch10_generics::lifetime",
+          "ch10_generics", "missing_lifetime", "E0106"),
+    case!("ch10_generics::lifetime",
+          "ch10_generics", "result_outlives_argument", "E0597"),
+    case!("ch10_generics::struct_lifetime",
+          "ch10_generics", "struct_missing_lifetime", "E0106"),
+    case!("ch10_generics::lifetime_elision",
+          "ch10_generics", "return_local_reference", "E0515"),
+    case!("ch10_generics::impl_lifetime",
+          "ch10_generics", "impl_missing_lifetime", "E0726"),
+    case!("ch10_generics::static_lifetime",
+          "ch10_generics", "temporary_as_static", "E0716"),
+    case!("ch10_generics::lifetime_bounds",
+          "ch10_generics", "missing_static_bound", "E0310"),
+    case!("ch13_closures::closure_syntax",
+          "ch13_closures", "inferred_type_mismatch", "E0308"),
+    case!("ch13_closures::fn_traits",
+          "ch13_closures", "mutate_in_fn", "E0594"),
+    case!("ch13_closures::fn_traits",
+          "ch13_closures", "move_out_of_fn_mut", "E0507"),
+    case!("ch13_closures::fn_traits",
+          "ch13_closures", "fn_once_as_fn", "E0525"),
+    case!("ch13_closures::move_into_thread",
+          "ch13_closures", "thread_borrow", "E0373"),
+    case!("ch15_smart_pointers::box_cons_list",
+          "ch15_smart_pointers", "recursive_without_box", "E0072"),
+    case!("ch15_smart_pointers::deref_trait",
+          "ch15_smart_pointers", "deref_without_trait", "E0614"),
+    case!("ch15_smart_pointers::drop_order",
+          "ch15_smart_pointers", "explicit_drop_call", "E0040"),
+    case!("ch15_smart_pointers::rc_shared",
+          "ch15_smart_pointers", "mutate_through_rc", "E0596"),
+    case!("ch16_concurrency::message_passing",
+          "ch16_concurrency", "use_after_send", "E0382"),
+    case!("ch16_concurrency::shared_state",
+          "ch16_concurrency", "rc_across_threads", "E0277"),
+    case!("ch16_concurrency::scoped_threads",
+          "ch16_concurrency", "unsynchronized_mutation", "E0499"),
+    case!("ch16_concurrency::scoped_threads",
+          "ch16_concurrency", "refcell_across_threads", "E0277"),
+];
+
+
+impl Case {
+    /// Path of the snippet, relative to the repository root.
+    pub fn path(&self) -> String {
+        let module: &str = self.example.split("::").next().unwrap_or_default();
+        format!("src/compile_fail/{}/{}.rs", module, self.name)
+    }
+}
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Verdict {
+    /// Rejected with the expected error code.
+    Rejected,
+    /// Compiled even though it should not.
+    Compiled,
+    /// Rejected, but not with the expected error code.
+    WrongErrors { codes: Vec<String>, stderr: String },
+}
+
+
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+
+/// Compiles `case` with the `rustc` named by `$RUSTC`, or found on the PATH.
+pub fn check(case: &Case) -> io::Result<Verdict> {
+    let dir: PathBuf = env::temp_dir().join(format!(
+        "syntax_examples-compile-fail-{}-{}",
+        process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed),
+    ));
+    fs::create_dir_all(&dir)?;
+    let file: PathBuf = dir.join(format!("{}.rs", case.name));
+    fs::write(&file, case.code)?;
+
+    let rustc: String = env::var("RUSTC").unwrap_or("rustc".to_string());
+    let output = Command::new(rustc)
+        .args(["--edition", "2021", "--crate-type", "bin", "--emit", "metadata"])
+        .args(["--error-format", "short", "--out-dir"])
+        .arg(&dir)
+        .arg(&file)
+        .output();
+    let _ = fs::remove_dir_all(&dir);
+    let output = output?;
+
+    if output.status.success() {
+        return Ok(Verdict::Compiled);
+    }
+
+    let stderr: String = String::from_utf8_lossy(&output.stderr).into_owned();
+    let codes: Vec<String> = error_codes(&stderr);
+    if codes.iter().any(|code| code == case.error) {
+        Ok(Verdict::Rejected)
+    } else {
+        Ok(Verdict::WrongErrors { codes, stderr })
+    }
+}
+
+
+/// The distinct `EXXXX` codes in `rustc` output, in order of appearance.
+pub fn error_codes(stderr: &str) -> Vec<String> {
+    let mut codes: Vec<String> = Vec::new();
+    for (i, _) in stderr.match_indices("error[E") {
+        let code: &str = &stderr[i + "error[".len()..];
+        if let Some(end) = code.find(']') {
+            let code: String = code[..end].to_string();
+            if !codes.contains(&code) {
+                codes.push(code);
+            }
+        }
+    }
+    codes
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn parse_error_codes() {
+        let stderr: &str = "a.rs:5:5: error[E0384]: cannot assign twice\n\
+                            a.rs:7:5: error[E0499]: cannot borrow\n\
+                            error: aborting due to 2 previous errors\n\
+                            Some errors have detailed explanations: E0384, E0499.\n";
+        assert_eq!(error_codes(stderr), ["E0384", "E0499"]);
+        assert!(error_codes("error: linking failed").is_empty());
+    }
+
+    #[test]
+    fn cases_belong_to_examples() {
+        for case in CASES {
+            assert!(registry::find(case.example).is_some(), "{}", case.example);
+            assert!(case.code.contains(&format!("error[{}]", case.error)), "{}", case.name);
+        }
+    }
+
+    #[test]
+    fn cases_follow_chapter_order() {
+        let chapters: Vec<u32> = CASES.iter()
+            .map(|case| registry::find(case.example).unwrap().chapter)
+            .collect();
+        assert!(chapters.is_sorted(), "{chapters:?}");
+    }
+
+    #[test]
+    fn rustc_rejects_every_case() {
+        for case in CASES {
+            assert_eq!(check(case).unwrap(), Verdict::Rejected, "{}", case.path());
+        }
+    }
+}
@@ -0,0 +1,16 @@
+// From `ch10_generics::impl_lifetime`: the lifetime parameter of the struct
+// must be declared after 'impl' and named after the struct.
+struct ImportantExcerpt<'a> {
+    part: &'a str,
+}
+
+impl ImportantExcerpt { // error[E0726]: implicit elided lifetime not allowed here
+    fn level(&self) -> i32 {
+        3
+    }
+}
+
+fn main() {
+    let excerpt = ImportantExcerpt { part: "Call me Ishmael." };
+    println!("{} {}", excerpt.part, excerpt.level());
+}
@@ -0,0 +1,9 @@
+// From `ch10_generics::lifetime`: the returned reference borrows from either
+// 'x' or 'y', and the compiler cannot tell which one without an annotation.
+fn longest(x: &str, y: &str) -> &str { // error[E0106]: missing lifetime specifier
+    if x.len() > y.len() { x } else { y }
+}
+
+fn main() {
+    assert_eq!(longest("bob", "alice"), "alice");
+}
@@ -0,0 +1,11 @@
+// From `ch10_generics::lifetime_bounds`: 'Box<dyn Display>' means
+// 'Box<dyn Display + 'static>', so 'T' must not hold short-lived references.
+use std::fmt::Display;
+
+fn boxed<T: Display>(x: T) -> Box<dyn Display> {
+    Box::new(x) // error[E0310]: the parameter type `T` may not live long enough
+}
+
+fn main() {
+    println!("{}", boxed(666));
+}
@@ -0,0 +1,15 @@
+// From `ch10_generics::lifetime`: the result of 'longest' lives only as long
+// as the shorter-lived argument, here 's2'.
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() > y.len() { x } else { y }
+}
+
+fn main() {
+    let s1: String = String::from("bob likes alice");
+    let result: &str;
+    {
+        let s2: String = String::from("alice likes rust");
+        result = longest(s1.as_str(), s2.as_str()); // error[E0597]: `s2` does not live long enough
+    }
+    println!("{}", result);
+}
@@ -0,0 +1,11 @@
+// From `ch10_generics::lifetime_elision`: by the second elision rule, the
+// result borrows from 's'. It cannot borrow from a local variable, which is
+// dropped when the function returns.
+fn shout(s: &str) -> &str {
+    let owned: String = s.to_uppercase();
+    &owned // error[E0515]: cannot return reference to local variable `owned`
+}
+
+fn main() {
+    println!("{}", shout("bob"));
+}
@@ -0,0 +1,11 @@
+// From `ch10_generics::struct_lifetime`: a field holding a reference needs a
+// lifetime, declared as a parameter of the struct.
+struct ImportantExcerpt {
+    part: &str, // error[E0106]: missing lifetime specifier
+}
+
+fn main() {
+    let novel: String = String::from("Call me Ishmael. Some years ago...");
+    let excerpt = ImportantExcerpt { part: novel.split('.').next().unwrap() };
+    println!("{}", excerpt.part);
+}
@@ -0,0 +1,6 @@
+// From `ch10_generics::static_lifetime`: a String built at runtime is freed at
+// the end of the statement, so it cannot be borrowed for the whole program.
+fn main() {
+    let s: &'static str = &String::from("bob"); // error[E0716]: temporary value dropped while borrowed
+    println!("{}", s);
+}
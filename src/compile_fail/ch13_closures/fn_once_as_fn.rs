@@ -0,0 +1,11 @@
+// From `ch13_closures::fn_traits`: a closure moving a captured value out can
+// only be called once, so it does not implement Fn.
+fn call_twice<F: Fn() -> String>(f: F) -> String {
+    f() + &f()
+}
+
+fn main() {
+    let name: String = String::from("bob");
+    let give_away = move || name; // error[E0525]: expected a closure that implements the `Fn` trait, but this closure only implements `FnOnce`
+    println!("{}", call_twice(give_away));
+}
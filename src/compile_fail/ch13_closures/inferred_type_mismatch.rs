@@ -0,0 +1,9 @@
+// From `ch13_closures::closure_syntax`: the parameter type of a closure is
+// inferred from its first call, and is then fixed.
+fn main() {
+    let example_closure = |x| x;
+
+    let s = example_closure(String::from("hello"));
+    let n = example_closure(5); // error[E0308]: mismatched types
+    println!("{s} {n}");
+}
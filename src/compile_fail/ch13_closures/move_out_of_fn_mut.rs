@@ -0,0 +1,13 @@
+// From `ch13_closures::fn_traits`: 'sort_by_key' calls its closure several
+// times, so the closure cannot move a captured value out.
+fn main() {
+    let mut list: Vec<(u32, u32)> = vec![(10, 1), (3, 5), (7, 12)];
+    let mut sort_operations: Vec<String> = vec![];
+    let value: String = String::from("closure called");
+
+    list.sort_by_key(|r| {
+        sort_operations.push(value); // error[E0507]: cannot move out of `value`, a captured variable in an `FnMut` closure
+        r.0
+    });
+    println!("{list:?}");
+}
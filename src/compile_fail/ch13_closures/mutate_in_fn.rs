@@ -0,0 +1,12 @@
+// From `ch13_closures::fn_traits`: a closure mutating its captures is FnMut,
+// not Fn.
+fn call_twice<F: Fn()>(f: F) {
+    f();
+    f();
+}
+
+fn main() {
+    let mut count: i32 = 0;
+    call_twice(|| count += 1); // error[E0594]: cannot assign to `count`, as it is a captured variable in a `Fn` closure
+    println!("{count}");
+}
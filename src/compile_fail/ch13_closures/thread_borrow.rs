@@ -0,0 +1,12 @@
+// From `ch13_closures::move_into_thread`: a spawned thread may outlive the
+// variables it borrows, so its closure must take ownership with 'move'.
+use std::thread;
+
+fn main() {
+    let list: Vec<i32> = vec![1, 2, 3];
+
+    let handle = thread::spawn(|| { // error[E0373]: closure may outlive the current function, but it borrows `list`
+        println!("{list:?}");
+    });
+    handle.join().unwrap();
+}
@@ -0,0 +1,16 @@
+// From `ch15_smart_pointers::deref_trait`: '*' only works on types that
+// implement 'Deref'.
+struct MyBox<T>(T);
+
+impl<T> MyBox<T> {
+    fn new(x: T) -> MyBox<T> {
+        MyBox(x)
+    }
+}
+
+fn main() {
+    let x: i32 = 5;
+    let y: MyBox<i32> = MyBox::new(x);
+
+    assert_eq!(5, *y); // error[E0614]: type `MyBox<i32>` cannot be dereferenced
+}
@@ -0,0 +1,17 @@
+// From `ch15_smart_pointers::drop_order`: 'Drop::drop' is called by the
+// compiler only; calling it too would drop the value twice.
+struct CustomSmartPointer {
+    data: String,
+}
+
+impl Drop for CustomSmartPointer {
+    fn drop(&mut self) {
+        println!("Dropping CustomSmartPointer with data `{}`!", self.data);
+    }
+}
+
+fn main() {
+    let c = CustomSmartPointer { data: String::from("some data") };
+    c.drop(); // error[E0040]: explicit use of destructor method
+    println!("CustomSmartPointer dropped before the end of main.");
+}
@@ -0,0 +1,11 @@
+// From `ch15_smart_pointers::rc_shared`: 'Rc' only hands out shared
+// references, so the value inside cannot be mutated without a 'RefCell'.
+use std::rc::Rc;
+
+fn main() {
+    let shared: Rc<Vec<i32>> = Rc::new(vec![1, 2, 3]);
+    let other: Rc<Vec<i32>> = Rc::clone(&shared);
+
+    shared.push(4); // error[E0596]: cannot borrow data in an `Rc` as mutable
+    println!("{other:?}");
+}
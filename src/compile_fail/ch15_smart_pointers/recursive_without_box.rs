@@ -0,0 +1,11 @@
+// From `ch15_smart_pointers::box_cons_list`: a type holding itself directly
+// would have an infinite size; the recursion needs a pointer such as 'Box'.
+#[derive(Debug)]
+enum List { // error[E0072]: recursive type `List` has infinite size
+    Cons(i32, List),
+    Nil,
+}
+
+fn main() {
+    println!("{:?}", List::Nil);
+}
@@ -0,0 +1,24 @@
+// From `ch16_concurrency::shared_state`: the count of an 'Rc' is not updated
+// atomically, so 'Rc' is not 'Send' and cannot be moved to another thread.
+use std::rc::Rc;
+use std::sync::Mutex;
+use std::thread;
+
+fn main() {
+    let counter = Rc::new(Mutex::new(0));
+    let mut handles = vec![];
+
+    for _ in 0..10 {
+        let counter = Rc::clone(&counter);
+        let handle = thread::spawn(move || { // error[E0277]: `Rc<Mutex<i32>>` cannot be sent between threads safely
+            let mut num = counter.lock().unwrap();
+            *num += 1;
+        });
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    println!("Result: {}", *counter.lock().unwrap());
+}
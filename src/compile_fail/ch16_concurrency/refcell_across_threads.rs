@@ -0,0 +1,15 @@
+// From `ch16_concurrency::scoped_threads`: 'RefCell' counts its borrows
+// without synchronization, so it is not 'Sync' and cannot be shared between
+// threads; use a 'Mutex' instead.
+use std::cell::RefCell;
+use std::thread;
+
+fn main() {
+    let counter: RefCell<i32> = RefCell::new(0);
+
+    thread::scope(|s| {
+        s.spawn(|| *counter.borrow_mut() += 1); // error[E0277]: `RefCell<i32>` cannot be shared between threads safely
+        s.spawn(|| *counter.borrow_mut() += 1);
+    });
+    println!("{}", counter.borrow());
+}
@@ -0,0 +1,13 @@
+// From `ch16_concurrency::scoped_threads`: two threads writing the same
+// variable would be a data race, so both cannot borrow it mutably.
+use std::thread;
+
+fn main() {
+    let mut counter: i32 = 0;
+
+    thread::scope(|s| {
+        s.spawn(|| counter += 1);
+        s.spawn(|| counter += 1); // error[E0499]: cannot borrow `counter` as mutable more than once at a time
+    });
+    println!("{counter}");
+}
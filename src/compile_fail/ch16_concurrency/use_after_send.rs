@@ -0,0 +1,17 @@
+// From `ch16_concurrency::message_passing`: 'send' moves the value into the
+// channel, and the receiving thread may already be changing it.
+use std::sync::mpsc;
+use std::thread;
+
+fn main() {
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let val = String::from("hi");
+        tx.send(val).unwrap();
+        println!("val is {val}"); // error[E0382]: borrow of moved value: `val`
+    });
+
+    let received = rx.recv().unwrap();
+    println!("Got: {received}");
+}
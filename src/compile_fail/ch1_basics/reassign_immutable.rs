@@ -0,0 +1,7 @@
+// From `ch1_basics::variable`: variables are immutable by default, so they
+// cannot be assigned to a second time.
+fn main() {
+    let x: i32 = 5;
+    x = x + 3; // error[E0384]: cannot assign twice to immutable variable `x`
+    assert_eq!(x, 8);
+}
@@ -0,0 +1,15 @@
+// From `ch4_ownership::slice_ownership`: 'clear' borrows 's' mutably while
+// '_word' still borrows it immutably.
+fn main() {
+    let mut s: String = String::from("bob likes alice");
+
+    let _word: &str = first_word(&s);
+
+    s.clear(); // error[E0502]: cannot borrow `s` as mutable
+
+    println!("{}", _word);
+}
+
+fn first_word(s: &str) -> &str {
+    s.split(' ').next().unwrap_or("")
+}
@@ -0,0 +1,14 @@
+// From `ch4_ownership::immutable_references`: a value cannot be moved into a
+// function while a reference to it is still in use.
+fn main() {
+    let s: String = String::from("bob");
+    let ref_of_s: &String = &s;
+
+    let _len: usize = calculate_length_and_take_ownership(s); // error[E0505]
+
+    println!("{}", ref_of_s);
+}
+
+fn calculate_length_and_take_ownership(s: String) -> usize {
+    s.len()
+}
@@ -0,0 +1,11 @@
+// From `ch4_ownership::switch_between_references`: there can only be one
+// mutable reference to a value at a time.
+fn main() {
+    let mut bob_name: String = String::from("bob");
+
+    let mutab_1: &mut String = &mut bob_name;
+    let mutab_2: &mut String = &mut bob_name; // error[E0499]
+
+    mutab_1.push_str(" likes alice");
+    mutab_2.push_str(" likes rust");
+}
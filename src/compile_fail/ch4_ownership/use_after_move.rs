@@ -0,0 +1,7 @@
+// From `ch4_ownership::shallow_transfer`: after the assignment, the string is
+// owned by '_b', and '_a' can no longer be used.
+fn main() {
+    let _a: String = String::from("bob");
+    let _b: String = _a;
+    println!("{}", _a); // error[E0382]: borrow of moved value: `_a`
+}
@@ -0,0 +1,22 @@
+// From `ch7_modules::struct_visibility`: the private field of a public struct
+// cannot be read outside its module.
+mod back_of_house {
+    pub struct Breakfast {
+        pub toast: String,
+        seasonal_fruit: String,
+    }
+
+    impl Breakfast {
+        pub fn summer(toast: &str) -> Breakfast {
+            Breakfast {
+                toast: String::from(toast),
+                seasonal_fruit: String::from("peaches"),
+            }
+        }
+    }
+}
+
+fn main() {
+    let meal = back_of_house::Breakfast::summer("rye");
+    println!("{} with {}", meal.toast, meal.seasonal_fruit); // error[E0616]: field `seasonal_fruit` of struct `Breakfast` is private
+}
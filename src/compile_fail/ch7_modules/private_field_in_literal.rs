@@ -0,0 +1,16 @@
+// From `ch7_modules::struct_visibility`: a struct with a private field cannot
+// be built with a struct literal outside its module.
+mod back_of_house {
+    pub struct Breakfast {
+        pub toast: String,
+        seasonal_fruit: String,
+    }
+}
+
+fn main() {
+    let meal = back_of_house::Breakfast {
+        toast: String::from("rye"),
+        seasonal_fruit: String::from("peaches"), // error[E0451]: field `seasonal_fruit` of struct `Breakfast` is private
+    };
+    println!("{}", meal.toast);
+}
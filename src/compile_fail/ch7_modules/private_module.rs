@@ -0,0 +1,14 @@
+// From `ch7_modules::visibility`: a private module, and even its public items,
+// can only be used by its parent and the parent's descendants.
+mod front_of_house {
+    mod serving {
+        pub fn take_order(dish: &str) -> String {
+            dish.to_string()
+        }
+    }
+}
+
+fn main() {
+    let order: String = front_of_house::serving::take_order("soup"); // error[E0603]: module `serving` is private
+    println!("{order}");
+}
@@ -0,0 +1,14 @@
+// From `ch7_modules::visibility`: a 'pub(super)' item is visible in the parent
+// module only, not in the grandparent.
+mod front_of_house {
+    pub mod hosting {
+        pub(super) fn free_tables() -> u32 {
+            1
+        }
+    }
+}
+
+fn main() {
+    let free: u32 = front_of_house::hosting::free_tables(); // error[E0603]: function `free_tables` is private
+    println!("{free}");
+}
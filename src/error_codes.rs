@@ -0,0 +1,392 @@
+//! The rustc errors that the examples are really about, each mapped to the
+//! examples explaining the rule behind it and to the compile-fail cases
+//! showing it.
+
+use crate::compile_fail::{self, Case};
+use crate::registry::{self, Example};
+
+
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorCode {
+    pub code: &'static str,
+    pub title: &'static str,
+    pub description: &'static str,
+    /// Ids of the examples explaining the rule behind the error.
+    pub examples: &'static [&'static str],
+    /// The smallest change that makes the failing code compile.
+    pub fix: &'static str,
+}
+
+
+pub const ERROR_CODES: &[ErrorCode] = &[
+    ErrorCode {
+        code: "E0040",
+        title: "explicit use of destructor method",
+        description: "\
+'Drop::drop' is called directly. Rust calls it when the value goes out of
+scope, so calling it too would drop the value twice.",
+        examples: &["ch15_smart_pointers::drop_order"],
+        fix: "\
+drop(c); // std::mem::drop takes ownership and drops the value now",
+    },
+    ErrorCode {
+        code: "E0072",
+        title: "recursive type has infinite size",
+        description: "\
+A struct or enum contains a value of its own type, directly, so its size
+cannot be computed. The recursion must go through a pointer.",
+        examples: &["ch15_smart_pointers::box_cons_list"],
+        fix: "\
+enum List {
+    Cons(i32, Box<List>), // a Box has a known size
+    Nil,
+}",
+    },
+    ErrorCode {
+        code: "E0106",
+        title: "missing lifetime specifier",
+        description: "\
+A function returns a reference, but the compiler cannot tell which argument
+it borrows from. The returned reference must be tied to the arguments with a
+lifetime parameter.",
+        examples: &["ch10_generics::lifetime", "ch10_generics::struct_lifetime"],
+        fix: "\
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() > y.len() { x } else { y }
+}",
+    },
+    ErrorCode {
+        code: "E0277",
+        title: "the trait bound is not satisfied",
+        description: "\
+A type is used where it must implement a trait it does not implement. For
+threads: values moved to another thread must be 'Send', and values shared
+between threads must be 'Sync'. 'Rc' is neither, since it updates its count
+without synchronization. 'RefCell' is 'Send' but not 'Sync': it can be moved
+to another thread, but its borrow tracking is not synchronized either, so
+share a 'Mutex' or 'RwLock' instead.",
+        examples: &["ch16_concurrency::shared_state", "ch16_concurrency::scoped_threads"],
+        fix: "\
+let counter = Arc::new(Mutex::new(0)); // atomic counts, and a lock
+let counter2 = Arc::clone(&counter);
+thread::spawn(move || *counter2.lock().unwrap() += 1);",
+    },
+    ErrorCode {
+        code: "E0308",
+        title: "mismatched types",
+        description: "\
+A value has another type than the one expected. For closures, this happens
+when a closure is called with another type than at its first call, which
+fixed the types of its parameters.",
+        examples: &["ch13_closures::closure_syntax"],
+        fix: "\
+let example_closure = |x| x;
+let s = example_closure(String::from(\"hello\"));
+let n = example_closure(5.to_string()); // the same type as the first call",
+    },
+    ErrorCode {
+        code: "E0310",
+        title: "a generic type may not live long enough",
+        description: "\
+A value of a generic type 'T' is kept somewhere that requires it to live for
+some lifetime, eg. in a 'Box<dyn Trait>' (which means 'Box<dyn Trait +
+'static>'), but 'T' might hold references that do not live that long.",
+        examples: &["ch10_generics::lifetime_bounds"],
+        fix: "\
+fn boxed<T: Display + 'static>(x: T) -> Box<dyn Display> {
+    Box::new(x)
+}",
+    },
+    ErrorCode {
+        code: "E0373",
+        title: "closure may outlive the current function",
+        description: "\
+A closure borrowing local variables is passed somewhere it may outlive them,
+eg. to 'thread::spawn'. The closure must take ownership of what it uses.",
+        examples: &["ch13_closures::move_into_thread"],
+        fix: "\
+let handle = thread::spawn(move || { // move 'list' into the thread
+    println!(\"{list:?}\");
+});",
+    },
+    ErrorCode {
+        code: "E0382",
+        title: "use of a moved value",
+        description: "\
+A value that lives on the heap, such as a String, was moved to another owner
+(by assignment or by passing it to a function) and then used again through
+its old name.",
+        examples: &[
+            "ch4_ownership::shallow_transfer",
+            "ch4_ownership::ownership_through_function",
+            "ch16_concurrency::message_passing",
+        ],
+        fix: "\
+let _a: String = String::from(\"bob\");
+let _b: String = _a.clone(); // copy the heap data instead of moving it
+println!(\"{}\", _a);",
+    },
+    ErrorCode {
+        code: "E0384",
+        title: "assignment twice to an immutable variable",
+        description: "\
+Bindings are immutable by default, so a variable declared with a plain 'let'
+cannot be assigned to again.",
+        examples: &["ch1_basics::variable"],
+        fix: "\
+let mut x: i32 = 5; // or shadow it: let x: i32 = x + 3;
+x = x + 3;",
+    },
+    ErrorCode {
+        code: "E0451",
+        title: "field is private",
+        description: "\
+A struct literal sets a field that is private to another module. A struct
+with private fields can only be built where they are visible, usually by a
+public constructor of that module.",
+        examples: &["ch7_modules::struct_visibility"],
+        fix: "\
+let meal = back_of_house::Breakfast::summer(\"rye\");",
+    },
+    ErrorCode {
+        code: "E0499",
+        title: "two mutable borrows at the same time",
+        description: "\
+There can only be one mutable reference to a value at a time: a second '&mut'
+was created while the first one is still used later on.",
+        examples: &[
+            "ch4_ownership::mutable_references",
+            "ch4_ownership::switch_between_references",
+            "ch16_concurrency::scoped_threads",
+        ],
+        fix: "\
+let mutab_1: &mut String = &mut bob_name;
+mutab_1.push_str(\" likes alice\"); // last use of mutab_1
+let mutab_2: &mut String = &mut bob_name;
+mutab_2.push_str(\" likes rust\");",
+    },
+    ErrorCode {
+        code: "E0502",
+        title: "mutable borrow while immutably borrowed",
+        description: "\
+A value was borrowed mutably (eg. by calling a '&mut self' method such as
+'clear') while an immutable reference to it is still used later on. Readers
+and a writer cannot coexist.",
+        examples: &[
+            "ch4_ownership::switch_between_references",
+            "ch4_ownership::slice_ownership",
+        ],
+        fix: "\
+let word: String = first_word(&s).to_string(); // own the data instead
+s.clear();
+println!(\"{}\", word);",
+    },
+    ErrorCode {
+        code: "E0505",
+        title: "move out of a borrowed value",
+        description: "\
+A value was moved (eg. into a function taking ownership) while a reference
+to it is still used later on.",
+        examples: &["ch4_ownership::immutable_references"],
+        fix: "\
+println!(\"{}\", ref_of_s); // finish using the reference first
+let _len: usize = calculate_length_and_take_ownership(s);",
+    },
+    ErrorCode {
+        code: "E0507",
+        title: "cannot move out of a borrowed or captured value",
+        description: "\
+A value is moved out of something only borrowed, such as a reference or the
+captures of an 'FnMut' closure, which may be called again afterwards.",
+        examples: &["ch13_closures::fn_traits"],
+        fix: "\
+list.sort_by_key(|r| {
+    sort_operations.push(value.clone()); // or count the calls instead
+    r.0
+});",
+    },
+    ErrorCode {
+        code: "E0515",
+        title: "returning a reference to a local variable",
+        description: "\
+A function returns a reference to a value it owns. That value is dropped when
+the function returns, so the reference would dangle. Lifetime annotations
+cannot extend how long a value lives.",
+        examples: &["ch10_generics::lifetime_elision", "ch10_generics::lifetime"],
+        fix: "\
+fn shout(s: &str) -> String {
+    s.to_uppercase() // return the owned value instead
+}",
+    },
+    ErrorCode {
+        code: "E0525",
+        title: "closure does not implement the expected Fn trait",
+        description: "\
+A closure is passed where 'Fn' or 'FnMut' is required, but its body moves a
+captured value out, so it can only be called once ('FnOnce').",
+        examples: &["ch13_closures::fn_traits"],
+        fix: "\
+let give_away = move || name.clone(); // clone instead of moving out",
+    },
+    ErrorCode {
+        code: "E0594",
+        title: "cannot assign to a captured variable of a Fn closure",
+        description: "\
+A closure mutating what it captures is 'FnMut', but it is passed where an
+'Fn' closure is required.",
+        examples: &["ch13_closures::fn_traits"],
+        fix: "\
+fn call_twice<F: FnMut()>(mut f: F) { // accept FnMut closures
+    f();
+    f();
+}",
+    },
+    ErrorCode {
+        code: "E0596",
+        title: "cannot borrow as mutable",
+        description: "\
+A value is mutated through something that only gives shared access, such as
+an immutable binding or an 'Rc'.",
+        examples: &["ch15_smart_pointers::rc_shared", "ch15_smart_pointers::refcell_borrows"],
+        fix: "\
+let shared: Rc<RefCell<Vec<i32>>> = Rc::new(RefCell::new(vec![1, 2, 3]));
+shared.borrow_mut().push(4); // mutable access checked at run time",
+    },
+    ErrorCode {
+        code: "E0597",
+        title: "borrowed value does not live long enough",
+        description: "\
+A reference is still used after the value it borrows from went out of scope,
+eg. the result of 'longest' after one of its arguments was dropped.",
+        examples: &["ch10_generics::lifetime", "ch10_generics::struct_lifetime"],
+        fix: "\
+let s2: String = String::from(\"alice likes rust\"); // declared in the outer scope
+let result: &str = longest(s1.as_str(), s2.as_str());
+println!(\"{}\", result);",
+    },
+    ErrorCode {
+        code: "E0603",
+        title: "item is private",
+        description: "\
+A path names an item, or goes through a module, that is not visible from
+here: it is private, or its 'pub(crate)', 'pub(super)' or 'pub(in path)'
+restriction leaves this module out.",
+        examples: &["ch7_modules::visibility"],
+        fix: "\
+mod front_of_house {
+    pub mod serving { // make the module public
+        pub fn take_order(dish: &str) -> String {
+            dish.to_string()
+        }
+    }
+}",
+    },
+    ErrorCode {
+        code: "E0614",
+        title: "type cannot be dereferenced",
+        description: "\
+'*' is applied to a value whose type is not a reference and does not
+implement 'Deref'.",
+        examples: &["ch15_smart_pointers::deref_trait"],
+        fix: "\
+impl<T> Deref for MyBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}",
+    },
+    ErrorCode {
+        code: "E0616",
+        title: "field is private",
+        description: "\
+An expression reads a field that is private to another module, even though
+the struct itself is public.",
+        examples: &["ch7_modules::struct_visibility"],
+        fix: "\
+impl Breakfast {
+    pub fn fruit(&self) -> &str { // or declare the field 'pub'
+        &self.seasonal_fruit
+    }
+}",
+    },
+    ErrorCode {
+        code: "E0716",
+        title: "temporary value dropped while borrowed",
+        description: "\
+A reference to a temporary value, such as the result of a function call, is
+used after the end of the statement that created the temporary.",
+        examples: &["ch10_generics::static_lifetime"],
+        fix: "\
+let owned: String = String::from(\"bob\"); // give the value a name
+let s: &str = &owned;",
+    },
+    ErrorCode {
+        code: "E0726",
+        title: "implicit elided lifetime not allowed here",
+        description: "\
+An 'impl' block names a struct that has a lifetime parameter without
+declaring that lifetime.",
+        examples: &["ch10_generics::impl_lifetime"],
+        fix: "\
+impl<'a> ImportantExcerpt<'a> {
+    fn level(&self) -> i32 {
+        3
+    }
+}",
+    },
+];
+
+
+/// Looks up a code, accepting eg. "E0502", "e0502" or "0502".
+pub fn find(code: &str) -> Option<&'static ErrorCode> {
+    let code: String = code.trim().to_uppercase();
+    let code: String = if code.starts_with('E') { code } else { format!("E{code}") };
+    ERROR_CODES.iter().find(|error| error.code == code)
+}
+
+
+impl ErrorCode {
+    pub fn examples(&self) -> Vec<&'static Example> {
+        self.examples.iter().filter_map(|id| registry::find(id)).collect()
+    }
+
+    /// The compile-fail cases that rustc rejects with this code.
+    pub fn cases(&self) -> Vec<&'static Case> {
+        compile_fail::CASES.iter().filter(|case| case.error == self.code).collect()
+    }
+
+    pub fn url(&self) -> String {
+        format!("https://doc.rust-lang.org/error_codes/{}.html", self.code)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_code_has_examples_and_cases() {
+        for error in ERROR_CODES {
+            assert_eq!(error.examples().len(), error.examples.len(), "{}", error.code);
+            assert!(!error.cases().is_empty(), "{}", error.code);
+        }
+    }
+
+    #[test]
+    fn every_case_is_indexed() {
+        for case in compile_fail::CASES {
+            assert!(find(case.error).is_some(), "{}", case.error);
+        }
+    }
+
+    #[test]
+    fn lookup() {
+        assert_eq!(find("E0502").unwrap().code, "E0502");
+        assert_eq!(find("e0502").unwrap().code, "E0502");
+        assert_eq!(find(" 0382 ").unwrap().code, "E0382");
+        assert!(find("E9999").is_none());
+    }
+}
@@ -0,0 +1,159 @@
+//! Checks what each example prints against a checked-in "golden" file.
+//!
+//! The expected output of `chN_xxx::name` lives in `golden/chN_xxx/name.stdout`.
+//! An example without such a file is expected to print nothing. Examples
+//! tagged "nondeterministic" (eg. iterating a HashMap) are not checked.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::capture::{self, Captured};
+use crate::registry::Example;
+use crate::runner::Outcome;
+
+
+pub const GOLDEN_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/golden");
+
+pub const NONDETERMINISTIC: &str = "nondeterministic";
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Verdict {
+    Matched,
+    Mismatched { diff: String },
+    Blessed,
+    /// The example itself failed; nothing to compare.
+    Failed(Outcome),
+    Skipped(String), // reason
+}
+
+
+pub fn path_of(example: &Example) -> PathBuf {
+    PathBuf::from(GOLDEN_DIR)
+        .join(example.module())
+        .join(format!("{}.stdout", example.name))
+}
+
+
+/// Expected output of `example`, which is empty when it has no golden file.
+pub fn expected(example: &Example) -> io::Result<String> {
+    match fs::read_to_string(path_of(example)) {
+        Ok(text) => Ok(text),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(String::new()),
+        Err(e) => Err(e),
+    }
+}
+
+
+/// Runs `example` and compares its output with the golden file, or with
+/// `bless` set, overwrites the golden file with the output.
+pub fn check(example: &Example, bless: bool) -> io::Result<Verdict> {
+    if example.tags.contains(&NONDETERMINISTIC) {
+        return Ok(Verdict::Skipped("output is nondeterministic".to_string()));
+    }
+
+    let captured: Captured = capture::run_captured(example)?;
+    if captured.outcome != Outcome::Passed {
+        return Ok(Verdict::Failed(captured.outcome));
+    }
+
+    if bless {
+        bless_output(example, &captured.stdout)?;
+        return Ok(Verdict::Blessed);
+    }
+
+    let expected: String = expected(example)?;
+    if expected == captured.stdout {
+        Ok(Verdict::Matched)
+    } else {
+        Ok(Verdict::Mismatched { diff: diff(&expected, &captured.stdout) })
+    }
+}
+
+
+fn bless_output(example: &Example, stdout: &str) -> io::Result<()> {
+    let path: PathBuf = path_of(example);
+
+    if stdout.is_empty() {
+        // no file means no output
+        match fs::remove_file(&path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+            _ => return Ok(()),
+        }
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, stdout)
+}
+
+
+/// Line diff from `expected` to `actual`: unchanged lines are prefixed with
+/// two spaces, removed ones with "- " and added ones with "+ ".
+pub fn diff(expected: &str, actual: &str) -> String {
+    let a: Vec<&str> = expected.lines().collect();
+    let b: Vec<&str> = actual.lines().collect();
+
+    // lcs[i][j] is the length of the longest common subsequence of a[i..]
+    // and b[j..].
+    let mut lcs: Vec<Vec<usize>> = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            out += &format!("  {}\n", a[i]);
+            i += 1;
+            j += 1;
+        } else if i < a.len() && (j == b.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            out += &format!("- {}\n", a[i]);
+            i += 1;
+        } else {
+            out += &format!("+ {}\n", b[j]);
+            j += 1;
+        }
+    }
+
+    if expected.ends_with('\n') != actual.ends_with('\n') && !expected.is_empty()
+        && !actual.is_empty() {
+        out += "(the outputs differ in their trailing newline)\n";
+    }
+    out
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn diff_marks_changed_lines() {
+        assert_eq!(diff("a\nb\nc\n", "a\nc\nd\n"), "  a\n- b\n  c\n+ d\n");
+        assert_eq!(diff("", "x\n"), "+ x\n");
+        assert_eq!(diff("x\n", ""), "- x\n");
+        assert_eq!(diff("1\n2\n", "1\n3\n"), "  1\n- 2\n+ 3\n");
+        assert_eq!(diff("a\n", "a"), "  a\n(the outputs differ in their trailing newline)\n");
+    }
+
+    #[test]
+    fn golden_paths() {
+        let example: &Example = registry::find("ch5_struct::special_struct").unwrap();
+        assert!(path_of(example).ends_with("golden/ch5_struct/special_struct.stdout"));
+        assert_eq!(expected(example).unwrap(), "Student { _name: \"bob\", _id: 666 }\n");
+
+        let silent: &Example = registry::find("ch4_ownership::slice").unwrap();
+        assert_eq!(expected(silent).unwrap(), "");
+    }
+}
@@ -15,6 +15,7 @@ mod effectful_unwrap_or {
   }
 
   #[test]
+  #[allow(clippy::let_unit_value, clippy::unit_arg, clippy::unnecessary_literal_unwrap)]
   fn effect() {
     let _ = Some(()).unwrap_or(boom()); // will explode
   }
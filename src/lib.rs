@@ -0,0 +1,49 @@
+//! Rust syntax examples following the chapters of The Book, together with
+//! a catalog of them and the tooling to run, check and search them.
+//!
+//! Each `chN_*` module holds example functions and the helper types they
+//! use, and lists them in its `EXAMPLES` table. `registry` collects these
+//! tables, so other crates can look examples up and run them:
+//!
+//! ```
+//! use syntax_examples::registry::{self, Example};
+//!
+//! let example: &Example = registry::find("ch4_ownership::slice").unwrap();
+//! (example.run)();
+//! assert_eq!(example.book_url(), "https://doc.rust-lang.org/book/ch04-03-slices.html");
+//! ```
+
+pub mod ch1_basics;
+pub mod ch2_datatypes;
+pub mod ch3_control_flow;
+pub mod ch4_ownership;
+pub mod ch5_struct;
+pub mod ch6_enum;
+pub mod ch7_modules;
+pub mod ch8_collections;
+pub mod ch9_errors;
+pub mod ch10_generics;
+pub mod ch11_testing;
+pub mod ch12_minigrep;
+pub mod ch13_closures;
+pub mod ch13_iterators;
+pub mod ch15_smart_pointers;
+pub mod ch16_concurrency;
+
+mod gotchas;
+
+pub mod registry;
+pub mod book;
+pub mod select;
+pub mod path;
+pub mod runner;
+pub mod sandbox;
+pub mod capture;
+pub mod golden;
+pub mod report;
+pub mod profile;
+pub mod source;
+pub mod search;
+pub mod quiz;
+pub mod compile_fail;
+pub mod error_codes;
@@ -0,0 +1,214 @@
+//! A reading order of the examples that respects their prerequisites.
+//!
+//! Every example may list the ids of the examples to read before it. These
+//! links must form a DAG: `validate` reports unknown tags, unknown
+//! prerequisites and cycles, and `reading_order` sorts examples so that each
+//! one comes after everything it requires.
+
+use std::fmt;
+
+use crate::registry::{self, Example};
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Problem {
+    UnknownTag { example: String, tag: String },
+    UnknownPrerequisite { example: String, prerequisite: String },
+    /// Ids of the examples forming the cycle, the first one repeated last.
+    Cycle(Vec<String>),
+}
+
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Problem::UnknownTag { example, tag } => {
+                write!(f, "{example}: unknown tag '{tag}'")
+            },
+            Problem::UnknownPrerequisite { example, prerequisite } => {
+                write!(f, "{example}: unknown prerequisite '{prerequisite}'")
+            },
+            Problem::Cycle(ids) => write!(f, "prerequisite cycle: {}", ids.join(" -> ")),
+        }
+    }
+}
+
+
+/// Checks the metadata of `examples` against `registry::TAGS` and each other.
+pub fn validate(examples: &[&Example]) -> Vec<Problem> {
+    let ids: Vec<String> = examples.iter().map(|e| e.id()).collect();
+    let mut problems: Vec<Problem> = Vec::new();
+
+    for (example, id) in examples.iter().zip(&ids) {
+        for tag in example.tags {
+            if !registry::TAGS.contains(tag) {
+                problems.push(Problem::UnknownTag { example: id.clone(), tag: tag.to_string() });
+            }
+        }
+        for prerequisite in example.requires {
+            if !ids.iter().any(|other| other == prerequisite) {
+                problems.push(Problem::UnknownPrerequisite {
+                    example: id.clone(),
+                    prerequisite: prerequisite.to_string(),
+                });
+            }
+        }
+    }
+
+    // Depth-first search; reaching an example still on the stack closes a cycle.
+    #[derive(Clone, Copy, PartialEq)]
+    enum Mark { New, OnStack, Done }
+
+    fn visit(
+        i: usize, edges: &[Vec<usize>], ids: &[String], marks: &mut [Mark],
+        stack: &mut Vec<usize>, problems: &mut Vec<Problem>,
+    ) {
+        marks[i] = Mark::OnStack;
+        stack.push(i);
+        for &j in &edges[i] {
+            match marks[j] {
+                Mark::New => visit(j, edges, ids, marks, stack, problems),
+                Mark::OnStack => {
+                    let start: usize = stack.iter().position(|&k| k == j).unwrap_or(0);
+                    let mut cycle: Vec<String> = stack[start..].iter()
+                        .map(|&k| ids[k].clone())
+                        .collect();
+                    cycle.push(ids[j].clone());
+                    problems.push(Problem::Cycle(cycle));
+                },
+                Mark::Done => {},
+            }
+        }
+        stack.pop();
+        marks[i] = Mark::Done;
+    }
+
+    let edges: Vec<Vec<usize>> = prerequisites(examples, &ids);
+    let mut marks: Vec<Mark> = vec![Mark::New; examples.len()];
+    for i in 0..examples.len() {
+        if marks[i] == Mark::New {
+            visit(i, &edges, &ids, &mut marks, &mut Vec::new(), &mut problems);
+        }
+    }
+    problems
+}
+
+
+/// Indices of the known prerequisites of each example.
+fn prerequisites(examples: &[&Example], ids: &[String]) -> Vec<Vec<usize>> {
+    examples.iter()
+        .map(|example| {
+            example.requires.iter()
+                .filter_map(|req| ids.iter().position(|id| id == req))
+                .collect()
+        })
+        .collect()
+}
+
+
+/// The `selected` examples together with everything they require, directly
+/// or not, sorted so that prerequisites come first. Among examples that are
+/// ready at the same time, the earlier one in the registry comes first.
+pub fn reading_order(selected: &[&'static Example]) -> Result<Vec<&'static Example>, Problem> {
+    let all: Vec<&'static Example> = registry::all().collect();
+    let ids: Vec<String> = all.iter().map(|e| e.id()).collect();
+    let edges: Vec<Vec<usize>> = prerequisites(&all, &ids);
+
+    // pull in the prerequisites of the selection
+    let mut wanted: Vec<bool> = all.iter()
+        .map(|e| selected.iter().any(|s| s.id() == e.id()))
+        .collect();
+    let mut todo: Vec<usize> = (0..all.len()).filter(|&i| wanted[i]).collect();
+    while let Some(i) = todo.pop() {
+        for &j in &edges[i] {
+            if !wanted[j] {
+                wanted[j] = true;
+                todo.push(j);
+            }
+        }
+    }
+
+    // Kahn's algorithm, always taking the first ready example
+    let mut missing: Vec<usize> = edges.iter().map(Vec::len).collect();
+    let mut done: Vec<bool> = vec![false; all.len()];
+    let mut order: Vec<&'static Example> = Vec::new();
+    let count: usize = wanted.iter().filter(|w| **w).count();
+
+    while order.len() < count {
+        let Some(next) = (0..all.len()).find(|&i| wanted[i] && !done[i] && missing[i] == 0) else {
+            let stuck: Vec<String> = (0..all.len())
+                .filter(|&i| wanted[i] && !done[i])
+                .map(|i| ids[i].clone())
+                .collect();
+            return Err(Problem::Cycle(stuck));
+        };
+        done[next] = true;
+        order.push(all[next]);
+        for (i, reqs) in edges.iter().enumerate() {
+            missing[i] -= reqs.iter().filter(|&&j| j == next).count();
+        }
+    }
+    Ok(order)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noop() {}
+
+    #[test]
+    fn registry_is_valid() {
+        let examples: Vec<&Example> = registry::all().collect();
+        assert_eq!(validate(&examples), []);
+    }
+
+    #[test]
+    fn order_respects_prerequisites() {
+        let order: Vec<&Example> = reading_order(&registry::all().collect::<Vec<_>>()).unwrap();
+        assert_eq!(order.len(), registry::all().count());
+
+        let ids: Vec<String> = order.iter().map(|e| e.id()).collect();
+        for (i, example) in order.iter().enumerate() {
+            for req in example.requires {
+                let j: usize = ids.iter().position(|id| id == req).unwrap();
+                assert!(j < i, "{} comes before {}", example.id(), req);
+            }
+        }
+        assert_eq!(ids[0], "ch1_basics::variable");
+    }
+
+    #[test]
+    fn order_includes_prerequisites() {
+        let slice: &'static Example = registry::find("ch4_ownership::slice").unwrap();
+        let ids: Vec<String> = reading_order(&[slice]).unwrap().iter().map(|e| e.id()).collect();
+        assert_eq!(ids.last().unwrap(), "ch4_ownership::slice");
+        assert!(ids.contains(&"ch4_ownership::immutable_references".to_string()));
+        assert!(ids.contains(&"ch1_basics::variable".to_string()));
+        assert!(!ids.contains(&"ch4_ownership::slice_ownership".to_string()));
+    }
+
+    #[test]
+    fn cycles_are_rejected() {
+        let a = Example::new(1, "demo::m", "a", noop).requires(&["m::c"]);
+        let b = Example::new(1, "demo::m", "b", noop).requires(&["m::a"]);
+        let c = Example::new(1, "demo::m", "c", noop).requires(&["m::b"]);
+        let d = Example::new(1, "demo::m", "d", noop).requires(&["m::a", "m::e"]).tags(&["x"]);
+
+        assert_eq!(validate(&[&a, &b, &c, &d]), [
+            Problem::UnknownTag { example: "m::d".to_string(), tag: "x".to_string() },
+            Problem::UnknownPrerequisite {
+                example: "m::d".to_string(),
+                prerequisite: "m::e".to_string(),
+            },
+            Problem::Cycle(vec![
+                "m::a".to_string(), "m::c".to_string(), "m::b".to_string(), "m::a".to_string(),
+            ]),
+        ]);
+        assert_eq!(
+            Problem::Cycle(vec!["m::a".to_string(), "m::a".to_string()]).to_string(),
+            "prerequisite cycle: m::a -> m::a",
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! Timing statistics of a run, to notice examples that got slower (eg. after
+//! a toolchain upgrade).
+
+use std::cmp::Reverse;
+use std::time::Duration;
+
+use crate::runner::Report;
+
+
+pub fn mean(samples: &[Duration]) -> Duration {
+    if samples.is_empty() {
+        return Duration::ZERO;
+    }
+    samples.iter().sum::<Duration>() / samples.len() as u32
+}
+
+
+/// Population standard deviation.
+pub fn stddev(samples: &[Duration]) -> Duration {
+    if samples.len() < 2 {
+        return Duration::ZERO;
+    }
+    let mean: f64 = mean(samples).as_secs_f64();
+    let variance: f64 = samples.iter()
+        .map(|s| (s.as_secs_f64() - mean).powi(2))
+        .sum::<f64>() / samples.len() as f64;
+    Duration::from_secs_f64(variance.sqrt())
+}
+
+
+/// Share of each report in the total time of all of them, from 0 to 1.
+pub fn shares(reports: &[Report]) -> Vec<f64> {
+    let total: f64 = reports.iter().map(|r| r.duration.as_secs_f64()).sum();
+    reports.iter()
+        .map(|r| if total > 0.0 { r.duration.as_secs_f64() / total } else { 0.0 })
+        .collect()
+}
+
+
+fn micros(d: Duration) -> String {
+    format!("{:.1} µs", d.as_secs_f64() * 1e6)
+}
+
+
+/// Prints the `top` slowest examples by mean time.
+pub fn print_profile(reports: &[Report], top: usize) {
+    let shares: Vec<f64> = shares(reports);
+    let mut order: Vec<usize> = (0..reports.len())
+        .filter(|&i| !reports[i].samples.is_empty())
+        .collect();
+    order.sort_by_key(|&i| Reverse(reports[i].duration));
+
+    let runs: usize = reports.iter().map(|r| r.samples.len()).max().unwrap_or(0);
+    println!();
+    println!("profile ({} slowest, {} run{} each):", top.min(order.len()), runs,
+             if runs == 1 { "" } else { "s" });
+    println!("  {:<44} {:>12} {:>14} {:>7}", "example", "mean", "spread", "share");
+
+    for &i in order.iter().take(top) {
+        let report: &Report = &reports[i];
+        println!(
+            "  {:<44} {:>12} {:>14} {:>6.1}%",
+            report.example.id(),
+            micros(report.duration),
+            format!("± {}", micros(stddev(&report.samples))),
+            shares[i] * 100.0,
+        );
+    }
+
+    let total: Duration = reports.iter().map(|r| r.duration).sum();
+    println!("  total of the means: {}", micros(total));
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+    use crate::runner::Outcome;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn statistics() {
+        assert_eq!(mean(&[]), Duration::ZERO);
+        assert_eq!(mean(&[ms(2), ms(4), ms(6)]), ms(4));
+        assert_eq!(stddev(&[ms(5)]), Duration::ZERO);
+        assert_eq!(stddev(&[ms(2), ms(4), ms(4), ms(4), ms(5), ms(5), ms(7), ms(9)]), ms(2));
+    }
+
+    #[test]
+    fn shares_of_total() {
+        let report = |id: &str, n: u64| Report {
+            example: registry::find(id).unwrap(),
+            outcome: Outcome::Passed,
+            duration: ms(n),
+            samples: vec![ms(n)],
+            stdout: None,
+        };
+        let reports: Vec<Report> = vec![
+            report("ch4_ownership::slice", 1),
+            report("ch8_collections::hashmap_syntax", 3),
+        ];
+        assert_eq!(shares(&reports), [0.25, 0.75]);
+        assert_eq!(shares(&[]), Vec::<f64>::new());
+    }
+}
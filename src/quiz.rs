@@ -0,0 +1,488 @@
+//! Questions about the examples, asked one after another on the terminal.
+//!
+//! The questions are built from the examples: each of `TEMPLATES` shows lines
+//! of its example's source, and each compile-fail case becomes a "does this
+//! compile?" question.
+
+use std::io::{self, BufRead, Write};
+
+use crate::compile_fail::{self, Case};
+use crate::registry::{self, Example};
+use crate::source::{self, Snippet};
+
+
+#[derive(Clone, Copy, Debug)]
+pub enum Answer {
+    /// Pick one of `options`; `correct` is an index into it.
+    Choice { options: &'static [&'static str], correct: usize },
+    /// Type the answer; whitespace and case are ignored when comparing.
+    Typed(&'static str),
+}
+
+
+/// Lines of an example's source, as found by `Snippet::excerpt`.
+#[derive(Clone, Copy, Debug)]
+pub struct Excerpt {
+    pub from: &'static str,
+    pub to: &'static str,
+}
+
+
+const fn lines(from: &'static str, to: &'static str) -> Excerpt {
+    Excerpt { from, to }
+}
+
+const fn line(text: &'static str) -> Excerpt {
+    Excerpt { from: text, to: text }
+}
+
+
+/// A question written about an example: the prompt, answer and explanation
+/// are written by hand, while the code comes from the example's source.
+#[derive(Clone, Copy, Debug)]
+pub struct Template {
+    /// Id of the example the question is about.
+    pub example: &'static str,
+    pub prompt: &'static str,
+    /// The parts of the example's snippet shown below the prompt.
+    pub code: &'static [Excerpt],
+    pub answer: Answer,
+    pub explanation: &'static str,
+}
+
+
+#[derive(Clone, Debug)]
+pub struct Question {
+    /// Id of the example the question is about.
+    pub example: &'static str,
+    pub prompt: &'static str,
+    /// Code shown below the prompt, if any.
+    pub code: String,
+    pub answer: Answer,
+    pub explanation: String,
+}
+
+
+const COMPILES: &[&str] = &["yes, it compiles", "no, it is rejected by the compiler"];
+
+
+// "does this compile?" questions answered with 'no' come from the
+// compile-fail cases instead, see 'rejected'.
+pub const TEMPLATES: &[Template] = &[
+    Template {
+        example: "ch1_basics::scope",
+        prompt: "What is the value of 'v' after the block?",
+        code: &[lines("let v: i32 = 233;", "{")],
+        answer: Answer::Typed("233"),
+        explanation: "The inner 'v' shadows the outer one only until the end of its \
+                      block.",
+    },
+    Template {
+        example: "ch2_datatypes::tuple",
+        prompt: "What is 'tup.1'?",
+        code: &[line("let tup: (i32, i32)")],
+        answer: Answer::Typed("66"),
+        explanation: "Tuple fields are numbered from 0.",
+    },
+    Template {
+        example: "ch3_control_flow::loop_syntax",
+        prompt: "What does 'loop_syntax' leave in 'y'?",
+        code: &[lines("let mut x: i32 = 3;", "let y: i32 = loop {")],
+        answer: Answer::Typed("20"),
+        explanation: "'x' counts up to 10, and 'break x * 2' makes the loop evaluate \
+                      to 20.",
+    },
+    Template {
+        example: "ch3_control_flow::for_and_while",
+        prompt: "What does 'a' hold after this loop?",
+        code: &[line("for index in 0..3")],
+        answer: Answer::Typed("[0, 1, 2]"),
+        explanation: "The range '0..3' excludes its end, so it yields 0, 1 and 2.",
+    },
+    Template {
+        example: "ch4_ownership::stack_copy",
+        prompt: "Does this compile?",
+        code: &[lines("let m: i32 = 50;", "assert_eq!(m, n);")],
+        answer: Answer::Choice { options: COMPILES, correct: 0 },
+        explanation: "Integers live on the stack and implement Copy, so 'm' is copied \
+                      rather than moved.",
+    },
+    Template {
+        example: "ch4_ownership::slice",
+        prompt: "What is '_rust'?",
+        code: &[lines("let s: &str = \"hello rust lang\";", "let _rust: &str")],
+        answer: Answer::Typed("rust"),
+        explanation: "Slices take the bytes from the start index up to, but not \
+                      including, the end index.",
+    },
+    Template {
+        example: "ch5_struct::struct_method",
+        prompt: "What is 'r.transpose().width' for 'r = Rectangle{width: 5, height: 3}'?",
+        code: &[line("pub fn transpose(&self) -> Self")],
+        answer: Answer::Typed("3"),
+        explanation: "'transpose' builds a new 'Rectangle' with 'width' and 'height' \
+                      swapped; 'r' itself is only borrowed.",
+    },
+    Template {
+        example: "ch6_enum::match_syntax",
+        prompt: "What is 'opt_max(None, Some(3))'?",
+        code: &[line("pub fn opt_max(")],
+        answer: Answer::Typed("Some(3)"),
+        explanation: "Neither of the first two arms matches, so the last one returns \
+                      'y', which is Some(3).",
+    },
+    Template {
+        example: "ch8_collections::string_syntax",
+        prompt: "Which of 'temp1' and 'temp2' can still be used afterwards?",
+        code: &[lines("let temp1 = String::from", "let s3 = temp1 + &temp2;")],
+        answer: Answer::Choice {
+            options: &["both", "only temp1", "only temp2", "neither"],
+            correct: 2,
+        },
+        explanation: "'+' takes its left operand by value and only borrows the right \
+                      one.",
+    },
+    Template {
+        example: "ch8_collections::hashmap_syntax",
+        prompt: "What is the price of \"potato\" at the end?",
+        code: &[
+            line("price.insert(\"potato\".to_string(), 10);"),
+            line("price.insert(\"potato\".to_string(), 15);"),
+            line("price.entry(\"potato\".to_string()).or_insert(20);"),
+        ],
+        answer: Answer::Choice { options: &["10", "15", "20"], correct: 1 },
+        explanation: "Inserting again overwrites the value, while 'entry().or_insert' \
+                      only inserts when the key is missing.",
+    },
+    Template {
+        example: "ch9_errors::err_propagation",
+        prompt: "What does '?' do when 'File::open' returns an Err?",
+        code: &[line("let mut f: File = File::open(\"temp.txt\")?;")],
+        answer: Answer::Choice {
+            options: &[
+                "it panics",
+                "it returns the Err from the enclosing function",
+                "it skips the line and carries on",
+            ],
+            correct: 1,
+        },
+        explanation: "'?' unwraps an Ok, and propagates an Err to the caller by \
+                      returning it early.",
+    },
+    Template {
+        example: "ch10_generics::trait_for_generic",
+        prompt: "With this 'PartialOrd' for 'Point', is 'p1 <= p2'?",
+        code: &[
+            line("impl<T: PartialOrd> PartialOrd for Point<T>"),
+            lines("let p1 = Point", "let p2 = Point"),
+        ],
+        answer: Answer::Choice { options: &["yes", "no"], correct: 0 },
+        explanation: "Points are compared by 'x' first, and only by 'y' when the 'x' \
+                      are equal.",
+    },
+    Template {
+        example: "ch11_testing::expected_panics",
+        prompt: "Does 'Guess::new(100)' panic?",
+        code: &[line("pub fn new(value: i32) -> Guess")],
+        answer: Answer::Choice { options: &["yes", "no"], correct: 1 },
+        explanation: "Both bounds are inclusive: only values below 1 or above 100 \
+                      panic, which is what a '#[should_panic]' test checks.",
+    },
+    Template {
+        example: "ch12_minigrep::search_function",
+        prompt: "What does 'minigrep::search(\"duct\", contents)' return?",
+        code: &[lines("let contents: &str = ", "Duct tape.")],
+        answer: Answer::Choice {
+            options: &[
+                "[\"safe, fast, productive.\"]",
+                "[\"safe, fast, productive.\", \"Duct tape.\"]",
+                "[]",
+            ],
+            correct: 0,
+        },
+        explanation: "'search' is case-sensitive: 'Duct tape.' does not contain \
+                      \"duct\". 'search_case_insensitive' would return both lines.",
+    },
+    Template {
+        example: "ch13_iterators::iterator_adapters",
+        prompt: "'Counter' yields 1 to 5. What is 'sum'?",
+        code: &[lines("let sum: u32 = Counter::new()", ".sum();")],
+        answer: Answer::Typed("18"),
+        explanation: "'zip' makes the pairs (1, 2), (2, 3), (3, 4) and (4, 5); of \
+                      their products 2, 6, 12 and 20, only 6 and 12 are multiples \
+                      of 3.",
+    },
+    Template {
+        example: "ch15_smart_pointers::drop_order",
+        prompt: "Which of '_c' and '_d' is dropped first at the end of the block?",
+        code: &[lines("let _c = CustomSmartPointer", "let _d = CustomSmartPointer")],
+        answer: Answer::Choice { options: &["_c", "_d"], correct: 1 },
+        explanation: "Variables are dropped in the reverse order of their creation.",
+    },
+];
+
+
+impl Template {
+    /// The question, with its code taken from the example's snippet; `None`
+    /// when the example or one of the excerpts cannot be found.
+    pub fn question(&self) -> Option<Question> {
+        let snippet: Snippet = source::snippet_of(registry::find(self.example)?)?;
+        let code: Vec<String> = self.code.iter()
+            .map(|excerpt| snippet.excerpt(excerpt.from, excerpt.to))
+            .collect::<Option<_>>()?;
+
+        Some(Question {
+            example: self.example,
+            prompt: self.prompt,
+            code: code.concat(),
+            answer: self.answer,
+            explanation: self.explanation.to_string(),
+        })
+    }
+}
+
+
+// A compile-fail case as a question. The comment heading the case explains
+// the error; the comment marking the rejected line would give it away.
+fn rejected(case: &Case) -> Question {
+    let mut comment: Vec<&str> = Vec::new();
+    let mut code = String::new();
+    for line in case.code.lines() {
+        match line.strip_prefix("//") {
+            Some(text) if code.is_empty() => comment.push(text.trim()),
+            _ => {
+                let line: &str = match line.find("// error[") {
+                    Some(marker) => line[..marker].trim_end(),
+                    None => line,
+                };
+                code += line;
+                code.push('\n');
+            },
+        }
+    }
+
+    // "From `ch4_ownership::shallow_transfer`: after the assignment, ..."
+    let comment: String = comment.join(" ");
+    let reason: &str = comment.split_once(": ").map_or(comment.as_str(), |(_, reason)| reason);
+    Question {
+        example: case.example,
+        prompt: "Does this compile?",
+        code,
+        answer: Answer::Choice { options: COMPILES, correct: 1 },
+        explanation: format!("{reason} rustc rejects it with error {}.", case.error),
+    }
+}
+
+
+/// Every question, in the order of the examples: those of `TEMPLATES`, and a
+/// "does this compile?" for each of `compile_fail::CASES`.
+pub fn questions() -> Vec<Question> {
+    let mut questions: Vec<Question> = TEMPLATES.iter()
+        .filter_map(Template::question)
+        .chain(compile_fail::CASES.iter().map(rejected))
+        .collect();
+
+    let ids: Vec<String> = registry::all().map(Example::id).collect();
+    questions.sort_by_key(|question| ids.iter().position(|id| id == question.example));
+    questions
+}
+
+
+impl Question {
+    pub fn example(&self) -> Option<&'static Example> {
+        registry::find(self.example)
+    }
+
+    pub fn is_correct(&self, reply: &str) -> bool {
+        match self.answer {
+            Answer::Choice { options, correct } => {
+                let reply: String = reply.trim().to_lowercase();
+                let letter: String = ((b'a' + correct as u8) as char).to_string();
+                reply == letter || reply == (correct + 1).to_string()
+                    || normalize(&reply) == normalize(options[correct])
+            },
+            Answer::Typed(expected) => normalize(reply) == normalize(expected),
+        }
+    }
+
+    fn expected(&self) -> String {
+        match self.answer {
+            Answer::Choice { options, correct } => {
+                format!("{}) {}", (b'a' + correct as u8) as char, options[correct])
+            },
+            Answer::Typed(expected) => expected.to_string(),
+        }
+    }
+}
+
+
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Score {
+    pub asked: usize,
+    pub correct: usize,
+}
+
+
+/// Asks `questions` in order, reading replies from `input`, until they run
+/// out or `input` ends.
+pub fn ask(
+    questions: &[Question], input: &mut impl BufRead, output: &mut impl Write,
+) -> io::Result<Score> {
+    let mut score = Score::default();
+
+    for (i, question) in questions.iter().enumerate() {
+        writeln!(output, "Question {}/{} ({})", i + 1, questions.len(), question.example)?;
+        writeln!(output, "{}", question.prompt)?;
+        if !question.code.is_empty() {
+            writeln!(output)?;
+            for line in question.code.lines() {
+                writeln!(output, "    {line}")?;
+            }
+        }
+        writeln!(output)?;
+        if let Answer::Choice { options, .. } = question.answer {
+            for (j, option) in options.iter().enumerate() {
+                writeln!(output, "  {}) {}", (b'a' + j as u8) as char, option)?;
+            }
+        }
+        write!(output, "answer> ")?;
+        output.flush()?;
+
+        let mut reply = String::new();
+        if input.read_line(&mut reply)? == 0 {
+            writeln!(output)?;
+            break;
+        }
+
+        score.asked += 1;
+        if question.is_correct(&reply) {
+            score.correct += 1;
+            writeln!(output, "correct!")?;
+        } else {
+            writeln!(output, "not quite, the answer is: {}", question.expected())?;
+        }
+        writeln!(output, "{}", question.explanation)?;
+        if let Some(example) = question.example() {
+            writeln!(output, "see `show {}` or {}", question.example, example.book_url())?;
+        }
+        writeln!(output)?;
+    }
+
+    writeln!(output, "score: {}/{}", score.correct, score.asked)?;
+    Ok(score)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    // the first question about `example`.
+    fn question(example: &str) -> Question {
+        questions().into_iter()
+            .find(|question| question.example == example)
+            .unwrap_or_else(|| panic!("no question about `{example}`"))
+    }
+
+    #[test]
+    fn templates_find_their_code() {
+        for template in TEMPLATES {
+            let question: Option<Question> = template.question();
+            assert!(question.is_some_and(|q| !q.code.is_empty()), "{}", template.example);
+            if let Answer::Choice { options, correct } = template.answer {
+                assert!(correct < options.len(), "{}", template.prompt);
+            }
+        }
+        assert_eq!(questions().len(), TEMPLATES.len() + compile_fail::CASES.len());
+    }
+
+    #[test]
+    fn code_comes_from_the_example() {
+        assert_eq!(
+            question("ch3_control_flow::loop_syntax").code,
+            "let mut x: i32 = 3;\n\nlet y: i32 = loop {\n\n    if x >= 10 {break x * 2;}\n\n    \
+             x += 1;\n};\n",
+        );
+        let code: String = question("ch6_enum::match_syntax").code; // an item it shows
+        assert!(code.starts_with("pub fn opt_max(x: Option<i32>, y: Option<i32>) -> Option<i32> {\n"));
+        assert!(code.ends_with("        _ => y,\n    }\n}\n"));
+    }
+
+    #[test]
+    fn compile_fail_cases() {
+        let question: Question = question("ch4_ownership::shallow_transfer");
+        assert_eq!(
+            question.code,
+            "fn main() {\n    let _a: String = String::from(\"bob\");\n    let _b: String = _a;\n    \
+             println!(\"{}\", _a);\n}\n",
+        );
+        assert!(question.explanation.starts_with("after the assignment, the string is owned"));
+        assert!(question.explanation.ends_with("rustc rejects it with error E0382."));
+
+        for question in questions().iter().filter(|q| q.prompt == "Does this compile?") {
+            assert!(!question.code.contains("error["), "{}", question.example);
+            assert!(!question.explanation.contains("From `"), "{}", question.example);
+        }
+    }
+
+    #[test]
+    fn every_chapter_is_asked_about() {
+        let questions: Vec<Question> = questions();
+        for chapter in registry::CHAPTERS {
+            let prefix: String = format!("{}::", chapter.module);
+            let asked: bool = questions.iter().any(|q| q.example.starts_with(&prefix));
+            assert!(asked, "{}", chapter.module);
+        }
+    }
+
+    #[test]
+    fn replies() {
+        let typed: Question = question("ch3_control_flow::for_and_while"); // [0, 1, 2]
+        assert!(typed.is_correct("[0,1,2]\n"));
+        assert!(!typed.is_correct("[0, 1, 2, 3]"));
+
+        let choice: Question = question("ch1_basics::variable"); // does not compile
+        assert!(choice.is_correct("b"));
+        assert!(choice.is_correct(" B\n"));
+        assert!(choice.is_correct("2"));
+        assert!(choice.is_correct("No, it is rejected by the compiler"));
+        assert!(!choice.is_correct("a"));
+    }
+
+    #[test]
+    fn scripted_session() {
+        let questions: Vec<Question> = questions().into_iter()
+            .filter(|q| q.example.starts_with("ch3_control_flow::"))
+            .collect();
+        let mut input = Cursor::new("20\nno idea\n");
+        let mut output: Vec<u8> = Vec::new();
+
+        let score: Score = ask(&questions, &mut input, &mut output).unwrap();
+        assert_eq!(score, Score { asked: 2, correct: 1 });
+
+        let text: String = String::from_utf8(output).unwrap();
+        assert!(text.starts_with("Question 1/2 (ch3_control_flow::loop_syntax)\n"));
+        assert!(text.contains("correct!\n"));
+        assert!(text.contains("not quite, the answer is: [0, 1, 2]\n"));
+        assert!(text.contains("see `show ch3_control_flow::loop_syntax`"));
+        assert!(text.ends_with("score: 1/2\n"));
+    }
+
+    #[test]
+    fn input_ends_early() {
+        let mut input = Cursor::new("b\n"); // 'ch1_basics::variable' does not compile
+        let mut output: Vec<u8> = Vec::new();
+
+        let score: Score = ask(&questions(), &mut input, &mut output).unwrap();
+        assert_eq!(score, Score { asked: 1, correct: 1 });
+    }
+}
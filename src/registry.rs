@@ -0,0 +1,329 @@
+//! A catalog of every example in this repo, so that tools can enumerate,
+//! filter and describe them instead of calling each function by hand.
+//!
+//! Each `chN_*` module declares a `pub const EXAMPLES: &[Example]` table using
+//! the `example!` macro, and the table is listed once in `CHAPTERS` below.
+
+use crate::{
+    ch1_basics, ch2_datatypes, ch3_control_flow, ch4_ownership, ch5_struct,
+    ch6_enum, ch7_modules, ch8_collections, ch9_errors, ch10_generics,
+    ch11_testing, ch12_minigrep, ch13_closures, ch13_iterators,
+    ch15_smart_pointers, ch16_concurrency,
+};
+use crate::sandbox::Setup;
+
+
+/// Prefix of every link into The Book.
+pub const BOOK_URL: &str = "https://doc.rust-lang.org/book/";
+
+
+/// Every tag an example may carry. Concept tags such as "ownership" group
+/// examples across chapters; "nondeterministic" marks examples whose output
+/// varies from run to run.
+pub const TAGS: &[&str] = &[
+    "arrays", "borrowing", "clone", "closures", "collections", "concurrency",
+    "control-flow", "copy", "destructuring", "enums", "error-handling",
+    "expressions", "files", "functions", "generics", "hashmaps", "iterators",
+    "lifetimes", "loops", "methods", "modules", "move", "mutability",
+    "nondeterministic", "option", "ownership", "panic", "pattern-matching",
+    "references", "result", "scalars", "scope", "shadowing", "slices",
+    "smart-pointers", "strings", "structs", "testing", "traits", "tuples",
+    "types", "variables", "vectors", "visibility",
+];
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Difficulty {
+    Beginner,
+    Intermediate,
+    Advanced,
+}
+
+
+impl Difficulty {
+    pub fn name(self) -> &'static str {
+        match self {
+            Difficulty::Beginner => "beginner",
+            Difficulty::Intermediate => "intermediate",
+            Difficulty::Advanced => "advanced",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Difficulty> {
+        [Difficulty::Beginner, Difficulty::Intermediate, Difficulty::Advanced]
+            .into_iter()
+            .find(|d| d.name() == name)
+    }
+}
+
+
+/// Describes one example function of this cheatsheet.
+#[derive(Clone, Copy, Debug)]
+pub struct Example {
+    /// Chapter number, as in the `chN_*` module name.
+    pub chapter: u32,
+    /// Full module path of the example, as given by `module_path!()`.
+    pub module_path: &'static str,
+    /// Name of the example function.
+    pub name: &'static str,
+    /// The example itself.
+    pub run: fn(),
+    /// One-line description of what the example demonstrates.
+    pub summary: &'static str,
+    /// Keywords from `TAGS`, such as "ownership" or "pattern-matching".
+    pub tags: &'static [&'static str],
+    pub difficulty: Difficulty,
+    /// Ids of the examples to read before this one.
+    pub requires: &'static [&'static str],
+    /// Module-level items the example uses, such as a struct (along with its
+    /// 'impl' blocks) or a helper function, shown with its source.
+    pub shows: &'static [&'static str],
+    /// Page (and optionally anchor) of The Book, relative to `BOOK_URL`.
+    pub book: &'static str,
+    /// When set, the example runs inside a `Sandbox` prepared this way.
+    pub setup: Option<Setup>,
+}
+
+
+impl Example {
+    /// Use the `example!` macro instead, which fills in the module and name.
+    pub const fn new(
+        chapter: u32, module_path: &'static str, name: &'static str, run: fn()
+    ) -> Self {
+        Example {
+            chapter, module_path, name, run,
+            summary: "",
+            tags: &[],
+            difficulty: Difficulty::Beginner,
+            requires: &[],
+            shows: &[],
+            book: "",
+            setup: None,
+        }
+    }
+
+    pub const fn summary(self, summary: &'static str) -> Self {
+        Example { summary, ..self }
+    }
+
+    pub const fn tags(self, tags: &'static [&'static str]) -> Self {
+        Example { tags, ..self }
+    }
+
+    pub const fn difficulty(self, difficulty: Difficulty) -> Self {
+        Example { difficulty, ..self }
+    }
+
+    pub const fn requires(self, requires: &'static [&'static str]) -> Self {
+        Example { requires, ..self }
+    }
+
+    pub const fn shows(self, shows: &'static [&'static str]) -> Self {
+        Example { shows, ..self }
+    }
+
+    pub const fn book(self, book: &'static str) -> Self {
+        Example { book, ..self }
+    }
+
+    pub const fn setup(self, setup: Setup) -> Self {
+        Example { setup: Some(setup), ..self }
+    }
+
+    /// Module name without the crate prefix, eg. `ch4_ownership`.
+    pub fn module(&self) -> &'static str {
+        match self.module_path.split_once("::") {
+            Some((_crate, module)) => module,
+            None => self.module_path,
+        }
+    }
+
+    /// Unique identifier, eg. `ch4_ownership::slice`.
+    pub fn id(&self) -> String {
+        format!("{}::{}", self.module(), self.name)
+    }
+
+    /// Full link to the matching section of The Book.
+    pub fn book_url(&self) -> String {
+        format!("{}{}", BOOK_URL, self.book)
+    }
+}
+
+
+/// Declares an `Example` for the function `$name` in the current module.
+/// Further details are attached with the builder methods of `Example`.
+macro_rules! example {
+    ($chapter:literal, $name:ident) => {
+        $crate::registry::Example::new(
+            $chapter, module_path!(), stringify!($name), $name
+        )
+    };
+}
+pub(crate) use example;
+
+
+/// A `chN_*` module together with the examples it contributes.
+#[derive(Clone, Copy, Debug)]
+pub struct Chapter {
+    pub number: u32,
+    pub module: &'static str,
+    pub title: &'static str,
+    pub examples: &'static [Example],
+    /// The chapter's source file, embedded at build time.
+    pub source: &'static str,
+}
+
+
+/// Every chapter, in the order their examples should run.
+pub const CHAPTERS: &[Chapter] = &[
+    Chapter {
+        number: 1, module: "ch1_basics",
+        title: "Variables and Scope",
+        examples: ch1_basics::EXAMPLES,
+        source: include_str!("ch1_basics.rs"),
+    },
+    Chapter {
+        number: 2, module: "ch2_datatypes",
+        title: "Data Types",
+        examples: ch2_datatypes::EXAMPLES,
+        source: include_str!("ch2_datatypes.rs"),
+    },
+    Chapter {
+        number: 3, module: "ch3_control_flow",
+        title: "Functions and Control Flow",
+        examples: ch3_control_flow::EXAMPLES,
+        source: include_str!("ch3_control_flow.rs"),
+    },
+    Chapter {
+        number: 4, module: "ch4_ownership",
+        title: "Understanding Ownership",
+        examples: ch4_ownership::EXAMPLES,
+        source: include_str!("ch4_ownership.rs"),
+    },
+    Chapter {
+        number: 5, module: "ch5_struct",
+        title: "Structs",
+        examples: ch5_struct::EXAMPLES,
+        source: include_str!("ch5_struct.rs"),
+    },
+    Chapter {
+        number: 6, module: "ch6_enum",
+        title: "Enums and Pattern Matching",
+        examples: ch6_enum::EXAMPLES,
+        source: include_str!("ch6_enum.rs"),
+    },
+    Chapter {
+        number: 7, module: "ch7_modules",
+        title: "Packages, Crates and Modules",
+        examples: ch7_modules::EXAMPLES,
+        source: include_str!("ch7_modules.rs"),
+    },
+    Chapter {
+        number: 8, module: "ch8_collections",
+        title: "Common Collections",
+        examples: ch8_collections::EXAMPLES,
+        source: include_str!("ch8_collections.rs"),
+    },
+    Chapter {
+        number: 9, module: "ch9_errors",
+        title: "Error Handling",
+        examples: ch9_errors::EXAMPLES,
+        source: include_str!("ch9_errors.rs"),
+    },
+    Chapter {
+        number: 10, module: "ch10_generics",
+        title: "Generic Types, Traits, and Lifetimes",
+        examples: ch10_generics::EXAMPLES,
+        source: include_str!("ch10_generics.rs"),
+    },
+    Chapter {
+        number: 11, module: "ch11_testing",
+        title: "Writing Automated Tests",
+        examples: ch11_testing::EXAMPLES,
+        source: include_str!("ch11_testing.rs"),
+    },
+    Chapter {
+        number: 12, module: "ch12_minigrep",
+        title: "An I/O Project: Building a Command Line Program",
+        examples: ch12_minigrep::EXAMPLES,
+        source: include_str!("ch12_minigrep.rs"),
+    },
+    Chapter {
+        number: 13, module: "ch13_closures",
+        title: "Closures",
+        examples: ch13_closures::EXAMPLES,
+        source: include_str!("ch13_closures.rs"),
+    },
+    Chapter {
+        number: 13, module: "ch13_iterators",
+        title: "Iterators",
+        examples: ch13_iterators::EXAMPLES,
+        source: include_str!("ch13_iterators.rs"),
+    },
+    Chapter {
+        number: 15, module: "ch15_smart_pointers",
+        title: "Smart Pointers",
+        examples: ch15_smart_pointers::EXAMPLES,
+        source: include_str!("ch15_smart_pointers.rs"),
+    },
+    Chapter {
+        number: 16, module: "ch16_concurrency",
+        title: "Fearless Concurrency",
+        examples: ch16_concurrency::EXAMPLES,
+        source: include_str!("ch16_concurrency.rs"),
+    },
+];
+
+
+/// Every registered example, in chapter order.
+pub fn all() -> impl Iterator<Item = &'static Example> {
+    CHAPTERS.iter().flat_map(|chapter| chapter.examples.iter())
+}
+
+
+/// Looks up an example by its id, eg. `ch4_ownership::slice`.
+pub fn find(id: &str) -> Option<&'static Example> {
+    all().find(|example| example.id() == id)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn chapters_match_their_examples() {
+        for chapter in CHAPTERS {
+            for example in chapter.examples {
+                assert_eq!(example.chapter, chapter.number, "{}", example.id());
+                assert_eq!(example.module(), chapter.module, "{}", example.id());
+            }
+        }
+    }
+
+    #[test]
+    fn ids_are_unique() {
+        let mut seen: HashSet<String> = HashSet::new();
+        for example in all() {
+            assert!(seen.insert(example.id()), "duplicate {}", example.id());
+        }
+    }
+
+    #[test]
+    fn examples_are_described() {
+        for example in all() {
+            assert!(!example.summary.is_empty(), "{}", example.id());
+            assert!(!example.tags.is_empty(), "{}", example.id());
+            assert!(example.book.starts_with("ch"), "{}", example.id());
+        }
+    }
+
+    #[test]
+    fn find_by_id() {
+        let example: &Example = find("ch4_ownership::slice").unwrap();
+        assert_eq!(example.name, "slice");
+        assert_eq!(example.chapter, 4);
+        assert!(find("ch4_ownership::nope").is_none());
+    }
+}
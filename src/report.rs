@@ -0,0 +1,278 @@
+//! Machine-readable renderings of a run, for pipelines and dashboards.
+
+use std::fmt::Write;
+
+use crate::registry::CHAPTERS;
+use crate::profile;
+use crate::runner::{Outcome, Report, Totals};
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    JUnit,
+}
+
+
+impl Format {
+    pub fn parse(name: &str) -> Option<Format> {
+        match name {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            "junit" => Some(Format::JUnit),
+            _ => None,
+        }
+    }
+}
+
+
+fn status(outcome: &Outcome) -> &'static str {
+    match outcome {
+        Outcome::Passed => "passed",
+        Outcome::Failed(_) => "failed",
+        Outcome::Skipped(_) => "skipped",
+    }
+}
+
+
+/// A JSON document with one object per example and the totals:
+///
+/// ```text
+/// {"examples": [{"id": "ch4_ownership::slice", "chapter": 4,
+///                "status": "passed", "duration_ms": 0.012, "runs": 1,
+///                "stddev_ms": 0.0, "share": 0.05, "stdout": "",
+///                "panic": null, "skip_reason": null}, ...],
+///  "totals": {"passed": 1, "failed": 0, "skipped": 0}}
+/// ```
+pub fn to_json(reports: &[Report]) -> String {
+    let mut out = String::from("{\n  \"examples\": [");
+    let shares: Vec<f64> = profile::shares(reports);
+
+    for (i, report) in reports.iter().enumerate() {
+        let panic: String = match &report.outcome {
+            Outcome::Failed(failure) => format!(
+                "{{\"message\": {}, \"location\": {}}}",
+                json_string(&failure.message),
+                failure.location.as_deref().map_or("null".to_string(), json_string),
+            ),
+            _ => "null".to_string(),
+        };
+        let skip_reason: String = match &report.outcome {
+            Outcome::Skipped(reason) => json_string(reason),
+            _ => "null".to_string(),
+        };
+
+        let separator: &str = if i == 0 { "" } else { "," };
+        write!(
+            out,
+            "{separator}\n    {{\"id\": {}, \"chapter\": {}, \"status\": \"{}\", \
+             \"duration_ms\": {:.3}, \"runs\": {}, \"stddev_ms\": {:.3}, \"share\": {:.4}, \
+             \"stdout\": {}, \"panic\": {}, \"skip_reason\": {}}}",
+            json_string(&report.example.id()),
+            report.example.chapter,
+            status(&report.outcome),
+            report.duration.as_secs_f64() * 1000.0,
+            report.samples.len(),
+            profile::stddev(&report.samples).as_secs_f64() * 1000.0,
+            shares[i],
+            report.stdout.as_deref().map_or("null".to_string(), json_string),
+            panic,
+            skip_reason,
+        ).unwrap();
+    }
+
+    let totals = Totals::of(reports);
+    write!(
+        out,
+        "\n  ],\n  \"totals\": {{\"passed\": {}, \"failed\": {}, \"skipped\": {}}}\n}}\n",
+        totals.passed, totals.failed, totals.skipped,
+    ).unwrap();
+    out
+}
+
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out += "\\\"",
+            '\\' => out += "\\\\",
+            '\n' => out += "\\n",
+            '\r' => out += "\\r",
+            '\t' => out += "\\t",
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+
+/// A JUnit XML document with one `<testsuite>` per chapter module and one
+/// `<testcase>` per example.
+pub fn to_junit(reports: &[Report]) -> String {
+    let totals = Totals::of(reports);
+    let seconds = |reports: &[&Report]| -> f64 {
+        reports.iter().map(|r| r.duration.as_secs_f64()).sum()
+    };
+
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let all: Vec<&Report> = reports.iter().collect();
+    writeln!(
+        out,
+        "<testsuites name=\"syntax_examples\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.6}\">",
+        reports.len(), totals.failed, totals.skipped, seconds(&all),
+    ).unwrap();
+
+    for chapter in CHAPTERS {
+        let suite: Vec<&Report> = reports.iter()
+            .filter(|r| r.example.module() == chapter.module)
+            .collect();
+        if suite.is_empty() {
+            continue;
+        }
+
+        let failures: usize = suite.iter()
+            .filter(|r| matches!(r.outcome, Outcome::Failed(_))).count();
+        let skipped: usize = suite.iter()
+            .filter(|r| matches!(r.outcome, Outcome::Skipped(_))).count();
+        writeln!(
+            out,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.6}\">",
+            chapter.module, suite.len(), failures, skipped, seconds(&suite),
+        ).unwrap();
+
+        for report in suite {
+            write!(
+                out,
+                "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.6}\">",
+                xml_escape(report.example.name),
+                chapter.module,
+                report.duration.as_secs_f64(),
+            ).unwrap();
+
+            // child elements, each on its own line
+            let mut body = String::new();
+            match &report.outcome {
+                Outcome::Passed => {},
+                Outcome::Failed(failure) => {
+                    let first_line: &str = failure.message.lines().next().unwrap_or("");
+                    write!(
+                        body,
+                        "\n      <failure message=\"{}\">{}{}</failure>",
+                        xml_escape(first_line),
+                        failure.location.as_deref()
+                            .map_or(String::new(), |l| format!("at {}\n", xml_escape(l))),
+                        xml_escape(&failure.message),
+                    ).unwrap();
+                },
+                Outcome::Skipped(reason) => {
+                    write!(body, "\n      <skipped message=\"{}\"/>", xml_escape(reason)).unwrap();
+                },
+            }
+            if let Some(stdout) = report.stdout.as_deref().filter(|s| !s.is_empty()) {
+                write!(body, "\n      <system-out>{}</system-out>", xml_escape(stdout)).unwrap();
+            }
+
+            if !body.is_empty() {
+                out += &body;
+                out += "\n    ";
+            }
+            out += "</testcase>\n";
+        }
+
+        out += "  </testsuite>\n";
+    }
+
+    out += "</testsuites>\n";
+    out
+}
+
+
+fn xml_escape(s: &str) -> String {
+    let mut out = String::new();
+    for c in s.chars() {
+        match c {
+            '&' => out += "&amp;",
+            '<' => out += "&lt;",
+            '>' => out += "&gt;",
+            '"' => out += "&quot;",
+            '\'' => out += "&apos;",
+            // XML 1.0 forbids most control characters, even escaped.
+            c if (c as u32) < 0x20 && !matches!(c, '\n' | '\r' | '\t') => out.push('\u{fffd}'),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+    use crate::runner::Failure;
+    use std::time::Duration;
+
+    fn reports() -> Vec<Report> {
+        let report = |id: &str, outcome: Outcome, stdout: Option<&str>| Report {
+            example: registry::find(id).unwrap(),
+            outcome,
+            duration: Duration::from_millis(2),
+            samples: vec![Duration::from_millis(2)],
+            stdout: stdout.map(String::from),
+        };
+        vec![
+            report("ch4_ownership::slice", Outcome::Passed, Some("")),
+            report("ch5_struct::special_struct", Outcome::Passed,
+                   Some("Student { _name: \"bob\" }\n")),
+            report("ch9_errors::unwrap_expect", Outcome::Failed(Failure {
+                message: "called `Result::unwrap()` on <Err>".to_string(),
+                location: Some("src/ch9_errors.rs:49:43".to_string()),
+            }), None),
+            report("ch10_generics::lifetime", Outcome::Skipped("--skip".to_string()), None),
+        ]
+    }
+
+    #[test]
+    fn json_report() {
+        let json: String = to_json(&reports());
+        assert!(json.contains(
+            "{\"id\": \"ch4_ownership::slice\", \"chapter\": 4, \"status\": \"passed\", \
+             \"duration_ms\": 2.000, \"runs\": 1, \"stddev_ms\": 0.000, \"share\": 0.2500, \
+             \"stdout\": \"\", \"panic\": null, \"skip_reason\": null}"
+        ));
+        assert!(json.contains("\"stdout\": \"Student { _name: \\\"bob\\\" }\\n\""));
+        assert!(json.contains(
+            "\"panic\": {\"message\": \"called `Result::unwrap()` on <Err>\", \
+             \"location\": \"src/ch9_errors.rs:49:43\"}"
+        ));
+        assert!(json.contains("\"skip_reason\": \"--skip\""));
+        assert!(json.ends_with("\"totals\": {\"passed\": 2, \"failed\": 1, \"skipped\": 1}\n}\n"));
+    }
+
+    #[test]
+    fn json_escapes() {
+        assert_eq!(json_string("a\"b\\c\nd\u{1}"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+    }
+
+    #[test]
+    fn junit_report() {
+        let xml: String = to_junit(&reports());
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+            <testsuites name=\"syntax_examples\" tests=\"4\" failures=\"1\" skipped=\"1\""));
+        assert!(xml.contains(
+            "<testsuite name=\"ch4_ownership\" tests=\"1\" failures=\"0\" skipped=\"0\" time=\"0.002000\">\n\
+             \x20   <testcase name=\"slice\" classname=\"ch4_ownership\" time=\"0.002000\"></testcase>\n"
+        ));
+        assert!(xml.contains("<system-out>Student { _name: &quot;bob&quot; }\n</system-out>"));
+        assert!(xml.contains(
+            "<failure message=\"called `Result::unwrap()` on &lt;Err&gt;\">\
+             at src/ch9_errors.rs:49:43\ncalled `Result::unwrap()` on &lt;Err&gt;</failure>"
+        ));
+        assert!(xml.contains("<skipped message=\"--skip\"/>\n    </testcase>"));
+        assert!(xml.ends_with("  </testsuite>\n</testsuites>\n"));
+    }
+}
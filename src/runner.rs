@@ -0,0 +1,200 @@
+//! Runs examples one at a time, so that a panicking example is reported
+//! instead of aborting every example after it.
+
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+use std::time::Duration;
+
+use crate::registry::Example;
+use crate::sandbox::Sandbox;
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Failure {
+    pub message: String,
+    /// `file:line:column` of the panic, when known.
+    pub location: Option<String>,
+}
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+    Passed,
+    Failed(Failure),
+    Skipped(String), // reason
+}
+
+
+#[derive(Clone, Debug)]
+pub struct Report {
+    pub example: &'static Example,
+    pub outcome: Outcome,
+    /// Mean wall-clock time of `samples`.
+    pub duration: Duration,
+    /// Wall-clock time of each run; empty when the example was skipped.
+    pub samples: Vec<Duration>,
+    /// What the example printed, when it ran with its output captured.
+    pub stdout: Option<String>,
+}
+
+
+thread_local! {
+    // whether a panic on this thread happens inside 'run_one'
+    static CAPTURING: Cell<bool> = const { Cell::new(false) };
+    static LAST_PANIC: RefCell<Option<Failure>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+
+// The default hook prints the panic to stderr; inside 'run_one' we record it
+// instead and let the summary report it. Panics elsewhere (eg. in other
+// threads of 'cargo test') still go to the default hook.
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CAPTURING.with(Cell::get) {
+                let failure = Failure {
+                    message: info.payload_as_str()
+                        .unwrap_or("<non-string panic payload>")
+                        .to_string(),
+                    location: info.location().map(|l| l.to_string()),
+                };
+                LAST_PANIC.with(|last| *last.borrow_mut() = Some(failure));
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+
+/// Runs a single example, catching any panic it raises. Examples with a
+/// `setup` run inside a fresh `Sandbox`.
+pub fn run_one(example: &Example) -> Outcome {
+    install_hook();
+
+    let _sandbox: Option<Sandbox> = match example.setup.map(Sandbox::enter) {
+        None => None,
+        Some(Ok(sandbox)) => Some(sandbox),
+        Some(Err(e)) => return Outcome::Failed(Failure {
+            message: format!("cannot set up the sandbox: {e}"),
+            location: None,
+        }),
+    };
+
+    CAPTURING.with(|c| c.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(example.run));
+    CAPTURING.with(|c| c.set(false));
+
+    // taken in any case: a panic the example caught itself must not linger
+    // until the next failure.
+    let panic: Option<Failure> = LAST_PANIC.with(|last| last.borrow_mut().take());
+    match result {
+        Ok(()) => Outcome::Passed,
+        Err(_) => Outcome::Failed(
+            panic.unwrap_or(Failure {
+                message: "<panic message unavailable>".to_string(),
+                location: None,
+            })
+        ),
+    }
+}
+
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Totals {
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+}
+
+
+impl Totals {
+    pub fn of(reports: &[Report]) -> Self {
+        let mut totals = Totals::default();
+        for report in reports {
+            match report.outcome {
+                Outcome::Passed => totals.passed += 1,
+                Outcome::Failed(_) => totals.failed += 1,
+                Outcome::Skipped(_) => totals.skipped += 1,
+            }
+        }
+        totals
+    }
+}
+
+
+/// Prints one row per example followed by the totals.
+pub fn print_summary(reports: &[Report]) {
+    println!();
+    println!("summary:");
+    for report in reports {
+        let id: String = report.example.id();
+        match &report.outcome {
+            Outcome::Passed => println!("  PASS  {id}"),
+            Outcome::Failed(failure) => {
+                println!("  FAIL  {id}");
+                if let Some(location) = &failure.location {
+                    println!("        at {location}");
+                }
+                for line in failure.message.lines() {
+                    println!("        {line}");
+                }
+            },
+            Outcome::Skipped(reason) => println!("  SKIP  {id} ({reason})"),
+        }
+    }
+
+    let totals = Totals::of(reports);
+    println!(
+        "{} examples: {} passed, {} failed, {} skipped",
+        reports.len(), totals.passed, totals.failed, totals.skipped,
+    );
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::example;
+
+    fn fine() {}
+
+    fn broken() {
+        let v: Vec<i32> = Vec::new();
+        assert_eq!(v.len(), 1, "vector should not be empty");
+    }
+
+    fn recovers() {
+        let result = panic::catch_unwind(|| panic!("caught by the example"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn passing_example() {
+        assert_eq!(run_one(&example!(0, fine)), Outcome::Passed);
+    }
+
+    #[test]
+    fn panicking_example_is_caught() {
+        let outcome: Outcome = run_one(&example!(0, broken));
+
+        let Outcome::Failed(failure) = outcome else {
+            panic!("expected a failure, got {outcome:?}");
+        };
+        assert!(failure.message.contains("vector should not be empty"));
+        assert!(failure.location.unwrap().starts_with("src/runner.rs:"));
+
+        // the next run starts clean
+        assert_eq!(run_one(&example!(0, fine)), Outcome::Passed);
+    }
+
+    #[test]
+    fn panic_caught_by_the_example_is_forgotten() {
+        assert_eq!(run_one(&example!(0, recovers)), Outcome::Passed);
+        assert!(LAST_PANIC.with(|last| last.borrow().is_none()));
+    }
+}
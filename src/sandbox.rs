@@ -0,0 +1,141 @@
+//! A throwaway working directory for examples that touch the filesystem, such
+//! as the `temp.txt` examples of `ch9_errors`, or the environment.
+//!
+//! Entering a `Sandbox` creates a fresh directory under the system temporary
+//! directory, prepares it according to a `Setup`, and makes it the current
+//! directory. Dropping the sandbox restores the previous current directory,
+//! and any environment variable the setup cleared, and deletes everything the
+//! example left behind.
+
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+
+/// The state of the directory an example starts in.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Setup {
+    /// `path` exists and holds `contents`.
+    FilePresent { path: &'static str, contents: &'static str },
+    /// `path` does not exist.
+    FileAbsent { path: &'static str },
+    /// The environment variable `name` is not set. The example may set it:
+    /// its previous value comes back when the sandbox is dropped.
+    VarUnset { name: &'static str },
+}
+
+
+// The current directory and the environment are shared by the whole process,
+// so only one sandbox may be entered at a time (eg. when 'cargo test' runs
+// tests in parallel).
+static CWD_LOCK: Mutex<()> = Mutex::new(());
+
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+
+#[derive(Debug)]
+pub struct Sandbox {
+    dir: PathBuf,
+    previous_dir: PathBuf,
+    // a variable cleared by `Setup::VarUnset`, with its previous value.
+    previous_var: Option<(&'static str, Option<OsString>)>,
+    _lock: MutexGuard<'static, ()>,
+}
+
+
+impl Sandbox {
+    /// Creates the sandbox directory, applies `setup` and moves into it.
+    pub fn enter(setup: Setup) -> io::Result<Sandbox> {
+        let lock = CWD_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let dir: PathBuf = env::temp_dir().join(format!(
+            "syntax_examples-{}-{}",
+            process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed),
+        ));
+        fs::create_dir_all(&dir)?;
+
+        let previous_dir: PathBuf = env::current_dir()?;
+        let mut sandbox = Sandbox { dir, previous_dir, previous_var: None, _lock: lock };
+
+        match setup {
+            Setup::FilePresent { path, contents } => {
+                fs::write(sandbox.dir.join(path), contents)?;
+            },
+            Setup::FileAbsent { path } => {
+                debug_assert!(!sandbox.dir.join(path).exists());
+            },
+            Setup::VarUnset { name } => {
+                sandbox.previous_var = Some((name, env::var_os(name)));
+                env::remove_var(name);
+            },
+        }
+
+        env::set_current_dir(&sandbox.dir)?;
+        Ok(sandbox)
+    }
+}
+
+
+impl Drop for Sandbox {
+    fn drop(&mut self) {
+        // errors are ignored: there is no one to report them to.
+        let _ = env::set_current_dir(&self.previous_dir);
+        match self.previous_var.take() {
+            Some((name, Some(value))) => env::set_var(name, value),
+            Some((name, None)) => env::remove_var(name),
+            None => {},
+        }
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn file_present() {
+        let sandbox = Sandbox::enter(
+            Setup::FilePresent { path: "hello.txt", contents: "hi" }
+        ).unwrap();
+
+        assert_eq!(fs::read_to_string("hello.txt").unwrap(), "hi");
+        assert_eq!(
+            env::current_dir().unwrap().canonicalize().unwrap(),
+            sandbox.dir.canonicalize().unwrap(),
+        );
+    }
+
+    #[test]
+    fn cleaned_up_on_drop() {
+        let sandbox = Sandbox::enter(Setup::FileAbsent { path: "hello.txt" }).unwrap();
+        assert!(!Path::new("hello.txt").exists());
+        fs::write("junk.txt", "left behind").unwrap();
+        let dir: PathBuf = sandbox.dir.clone();
+        drop(sandbox);
+
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn var_restored_on_drop() {
+        const NAME: &str = "SYNTAX_EXAMPLES_SANDBOX_TEST";
+        env::set_var(NAME, "before");
+
+        let sandbox = Sandbox::enter(Setup::VarUnset { name: NAME }).unwrap();
+        assert!(env::var_os(NAME).is_none());
+        env::set_var(NAME, "during");
+        drop(sandbox);
+
+        assert_eq!(env::var(NAME).unwrap(), "before");
+        env::remove_var(NAME);
+    }
+}
@@ -0,0 +1,231 @@
+//! Full-text search over the comments and code of every example.
+//!
+//! A query is split into words, eg. `entry().or_insert` into "entry" and
+//! "or_insert". An example matches when each word starts some word of its
+//! source, summary, tags or name, where plain English words are stemmed, so
+//! "shadowing" also finds "shadow". Matches are ranked by where they occur:
+//! the name and tags weigh most, comments more than code, and lines holding
+//! every word of the query get a bonus.
+
+use std::cmp::Reverse;
+use std::ops::Range;
+
+use crate::registry::{self, Example};
+use crate::source::{self, Snippet};
+
+
+/// A line of an example that matches the query.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Line {
+    /// 1-based line number in the chapter file.
+    pub number: usize,
+    pub text: String,
+    /// Byte ranges of `text` that match a word of the query.
+    pub matches: Vec<Range<usize>>,
+    score: usize,
+}
+
+
+#[derive(Clone, Debug)]
+pub struct Hit {
+    pub example: &'static Example,
+    pub score: usize,
+    /// Matching lines, best first.
+    pub lines: Vec<Line>,
+}
+
+
+/// Splits a query or a line into lowercase words of letters, digits and `_`,
+/// with the byte range of each word.
+fn words(text: &str) -> Vec<(Range<usize>, String)> {
+    let mut out: Vec<(Range<usize>, String)> = Vec::new();
+    let mut start: Option<usize> = None;
+
+    for (i, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        let is_word: bool = c.is_alphanumeric() || c == '_';
+        match (start, is_word) {
+            (None, true) => start = Some(i),
+            (Some(s), false) => {
+                out.push((s..i, text[s..i].to_lowercase()));
+                start = None;
+            },
+            _ => {},
+        }
+    }
+    out
+}
+
+
+/// Drops a common English suffix, so that a term also matches other forms of
+/// the same word. Identifiers (anything with `_` or digits) are kept as is.
+fn stem(term: &str) -> &str {
+    if !term.chars().all(|c| c.is_ascii_alphabetic()) {
+        return term;
+    }
+    for suffix in ["ing", "ed", "es", "s"] {
+        if let Some(root) = term.strip_suffix(suffix) {
+            if root.len() >= 3 {
+                return root;
+            }
+        }
+    }
+    term
+}
+
+
+/// Which terms of `stems` occur in `text`, and where.
+fn find_terms(text: &str, stems: &[&str]) -> (Vec<bool>, Vec<Range<usize>>) {
+    let mut found: Vec<bool> = vec![false; stems.len()];
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+
+    for (range, word) in words(text) {
+        for (t, stem) in stems.iter().enumerate() {
+            if word.starts_with(stem) {
+                found[t] = true;
+                ranges.push(range.clone());
+                break;
+            }
+        }
+    }
+    (found, ranges)
+}
+
+
+pub fn search(query: &str) -> Vec<Hit> {
+    let terms: Vec<String> = words(query).into_iter().map(|(_, w)| w).collect();
+    if terms.is_empty() {
+        return Vec::new();
+    }
+    let stems: Vec<&str> = terms.iter().map(|t| stem(t)).collect();
+
+    let mut hits: Vec<Hit> = registry::all()
+        .filter_map(|example| score(example, &stems))
+        .collect();
+
+    // stable sort: equal scores stay in chapter order.
+    hits.sort_by_key(|hit| Reverse(hit.score));
+    hits
+}
+
+
+fn score(example: &'static Example, stems: &[&str]) -> Option<Hit> {
+    let snippet: Snippet = source::snippet_of(example)?;
+    let mut found: Vec<bool> = vec![false; stems.len()];
+    let mut total: usize = 0;
+
+    let mut describe = |text: &str, weight: usize| {
+        let (in_text, _) = find_terms(text, stems);
+        for (t, hit) in in_text.iter().enumerate() {
+            if *hit {
+                found[t] = true;
+                total += weight;
+            }
+        }
+    };
+    describe(example.name, 8);
+    describe(&example.tags.join(" "), 5);
+    describe(example.summary, 3);
+
+    let mut lines: Vec<Line> = Vec::new();
+
+    // the items shown with the example, then the example itself.
+    for part in snippet.items.iter().chain([&snippet]) {
+        let comment_lines: usize = part.comment.lines().count();
+
+        for (i, text) in part.comment.lines().chain(part.code.lines()).enumerate() {
+            let (in_line, matches) = find_terms(text, stems);
+            let count: usize = in_line.iter().filter(|hit| **hit).count();
+            if count == 0 {
+                continue;
+            }
+
+            let is_comment: bool = i < comment_lines || text.trim_start().starts_with("//");
+            let mut line_score: usize = count * if is_comment { 2 } else { 1 };
+            if count == stems.len() && stems.len() > 1 {
+                line_score += 3;
+            }
+
+            for (t, hit) in in_line.iter().enumerate() {
+                found[t] |= *hit;
+            }
+            total += line_score;
+            lines.push(Line {
+                number: part.line + i,
+                text: text.to_string(),
+                matches,
+                score: line_score,
+            });
+        }
+    }
+
+    if !found.iter().all(|hit| *hit) {
+        return None;
+    }
+
+    lines.sort_by(|a, b| b.score.cmp(&a.score).then(a.number.cmp(&b.number)));
+    Some(Hit { example, score: total, lines })
+}
+
+
+/// Wraps every match of `line` between `open` and `close`.
+pub fn highlight(line: &Line, open: &str, close: &str) -> String {
+    let mut out = String::new();
+    let mut last: usize = 0;
+    for range in &line.matches {
+        out += &line.text[last..range.start];
+        out += open;
+        out += &line.text[range.clone()];
+        out += close;
+        last = range.end;
+    }
+    out += &line.text[last..];
+    out
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(hits: &[Hit]) -> Vec<String> {
+        hits.iter().map(|hit| hit.example.id()).collect()
+    }
+
+    #[test]
+    fn split_into_words() {
+        let found: Vec<String> = words("price.entry(n).or_insert(0);")
+            .into_iter().map(|(_, w)| w).collect();
+        assert_eq!(found, ["price", "entry", "n", "or_insert", "0"]);
+        assert_eq!(stem("shadowing"), "shadow");
+        assert_eq!(stem("or_insert"), "or_insert");
+        assert_eq!(stem("is"), "is");
+    }
+
+    #[test]
+    fn identifier_query() {
+        let hits: Vec<Hit> = search("entry().or_insert");
+        assert_eq!(ids(&hits)[0], "ch8_collections::hashmap_syntax");
+
+        let best: &Line = &hits[0].lines[0];
+        assert!(best.text.contains("entry") && best.text.contains("or_insert"));
+        assert_eq!(
+            highlight(best, "[", "]"),
+            best.text.replace("entry", "[entry]").replace("or_insert", "[or_insert]"),
+        );
+    }
+
+    #[test]
+    fn word_query() {
+        let hits: Vec<Hit> = search("shadowing");
+        assert_eq!(&ids(&hits)[..2], ["ch1_basics::variable", "ch1_basics::scope"]);
+        assert!(search("").is_empty());
+        assert!(search("xylophone").is_empty());
+    }
+
+    #[test]
+    fn every_term_must_match() {
+        let both: Vec<String> = ids(&search("mutable slice"));
+        assert!(both.contains(&"ch4_ownership::slice_ownership".to_string()));
+        assert!(!both.contains(&"ch4_ownership::slice".to_string()));
+    }
+}
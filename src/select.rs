@@ -0,0 +1,125 @@
+//! Picks examples out of the registry by id pattern, chapter, tag and
+//! difficulty.
+
+use crate::registry::{self, Difficulty, Example};
+
+
+/// A set of criteria on examples. An example is selected when it matches at
+/// least one pattern, one chapter, one tag and one difficulty, where an empty
+/// list of criteria matches everything.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Selection {
+    pub patterns: Vec<String>,
+    pub chapters: Vec<u32>,
+    pub tags: Vec<String>,
+    pub difficulties: Vec<Difficulty>,
+}
+
+
+impl Selection {
+    pub fn matches(&self, example: &Example) -> bool {
+        let pattern_ok: bool = self.patterns.is_empty()
+            || self.patterns.iter().any(|p| matches_pattern(p, example));
+        let chapter_ok: bool = self.chapters.is_empty()
+            || self.chapters.contains(&example.chapter);
+        let tag_ok: bool = self.tags.is_empty()
+            || self.tags.iter().any(|t| example.tags.contains(&t.as_str()));
+        let difficulty_ok: bool = self.difficulties.is_empty()
+            || self.difficulties.contains(&example.difficulty);
+
+        pattern_ok && chapter_ok && tag_ok && difficulty_ok
+    }
+
+    /// Selected examples, in registry order.
+    pub fn examples(&self) -> Vec<&'static Example> {
+        registry::all().filter(|example| self.matches(example)).collect()
+    }
+}
+
+
+/// A pattern containing `::` is matched against the whole id, where the
+/// module may also be abbreviated to its chapter, eg. `ch8::*map*`. Otherwise
+/// it is matched against the example name only, eg. `*slice*`.
+pub fn matches_pattern(pattern: &str, example: &Example) -> bool {
+    if pattern.contains("::") {
+        let short_id: String = format!("ch{}::{}", example.chapter, example.name);
+        glob(pattern, &example.id()) || glob(pattern, &short_id)
+    } else {
+        glob(pattern, example.name)
+    }
+}
+
+
+/// Shell-style wildcard matching: `*` matches any run of characters and `?`
+/// matches exactly one.
+pub fn glob(pattern: &str, text: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let t: Vec<char> = text.chars().collect();
+
+    // Greedy matching with backtracking to the most recent '*'.
+    let (mut i, mut j) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+
+    while j < t.len() {
+        if i < p.len() && (p[i] == '?' || p[i] == t[j]) {
+            i += 1;
+            j += 1;
+        } else if i < p.len() && p[i] == '*' {
+            star = Some((i, j));
+            i += 1;
+        } else if let Some((si, sj)) = star {
+            i = si + 1;
+            j = sj + 1;
+            star = Some((si, sj + 1));
+        } else {
+            return false;
+        }
+    }
+
+    p[i..].iter().all(|&c| c == '*')
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_wildcards() {
+        assert!(glob("slice", "slice"));
+        assert!(!glob("slice", "slice_ownership"));
+        assert!(glob("slice*", "slice_ownership"));
+        assert!(glob("*map*", "hashmap_syntax"));
+        assert!(glob("?ap", "map"));
+        assert!(!glob("?ap", "ap"));
+        assert!(glob("*", ""));
+        assert!(!glob("a*b", "acbd"));
+    }
+
+    #[test]
+    fn patterns_on_ids() {
+        let hashmap: &Example = registry::find("ch8_collections::hashmap_syntax").unwrap();
+        assert!(matches_pattern("ch8::*map*", hashmap));
+        assert!(matches_pattern("ch8_collections::*", hashmap));
+        assert!(matches_pattern("hashmap_syntax", hashmap));
+        assert!(!matches_pattern("ch9::*", hashmap));
+    }
+
+    #[test]
+    fn combined_criteria() {
+        let selection = Selection {
+            patterns: vec![],
+            chapters: vec![4],
+            tags: vec!["slices".to_string()],
+            difficulties: vec![],
+        };
+        let ids: Vec<String> = selection.examples().iter().map(|e| e.id()).collect();
+        assert_eq!(ids, ["ch4_ownership::slice", "ch4_ownership::slice_ownership"]);
+
+        let advanced = Selection { difficulties: vec![Difficulty::Advanced], ..selection };
+        let ids: Vec<String> = advanced.examples().iter().map(|e| e.id()).collect();
+        assert_eq!(ids, ["ch4_ownership::slice_ownership"]);
+
+        assert_eq!(Selection::default().examples().len(), registry::all().count());
+    }
+}
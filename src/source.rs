@@ -0,0 +1,365 @@
+//! Finds an example's own source code, and the comment block explaining it,
+//! in the chapter files embedded into the binary at build time.
+
+use crate::registry::{self, Chapter, Example};
+
+
+/// The commentary and code of one example function.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Snippet {
+    /// The comment lines directly above the function, if any.
+    pub comment: String,
+    /// From the function's attributes and signature to its closing brace.
+    pub code: String,
+    /// 1-based line number of the first line of `comment` (or `code`).
+    pub line: usize,
+    /// The module-level items the example `shows`, in source order.
+    pub items: Vec<Snippet>,
+}
+
+
+impl Snippet {
+    /// The items, each followed by a blank line, then the comment and code.
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        for item in &self.items {
+            text += &item.text();
+            text.push('\n');
+        }
+        text + &self.comment + &self.code
+    }
+
+    /// The lines of `text()` from the first line of code containing `from` to
+    /// the next one containing `to`, extended until the braces opened in
+    /// between are closed. Comment lines are left out, and the indentation of
+    /// the first line is removed from every line.
+    pub fn excerpt(&self, from: &str, to: &str) -> Option<String> {
+        let text: String = self.text();
+        let lines: Vec<&str> = text.lines().collect();
+        let is_code = |line: &str| !line.trim_start().starts_with("//");
+
+        let first: usize = lines.iter()
+            .position(|line| is_code(line) && line.contains(from))?;
+        let mut last: usize = first + lines[first..].iter()
+            .position(|line| is_code(line) && line.contains(to))?;
+
+        let depth = |line: &str| -> i32 {
+            code_chars(line).iter()
+                .map(|c| match c {
+                    '{' => 1,
+                    '}' => -1,
+                    _ => 0,
+                })
+                .sum()
+        };
+        let mut open: i32 = lines[first..=last].iter().map(|line| depth(line)).sum();
+        while open > 0 && last + 1 < lines.len() {
+            last += 1;
+            open += depth(lines[last]);
+        }
+
+        let indent: &str = &lines[first][..lines[first].len() - lines[first].trim_start().len()];
+        Some(lines[first..=last].iter()
+            .filter(|line| is_code(line))
+            .map(|line| format!("{}\n", line.strip_prefix(indent).unwrap_or(line)))
+            .collect())
+    }
+
+    /// Whether the function body holds nothing but blanks and comments.
+    pub fn body_is_empty(&self) -> bool {
+        let (Some(open), Some(close)) = (self.code.find('{'), self.code.rfind('}')) else {
+            return false;
+        };
+        self.code[open + 1..close].lines()
+            .all(|line| code_chars(line).iter().all(|c| c.is_whitespace()))
+    }
+}
+
+
+pub fn chapter_of(example: &Example) -> Option<&'static Chapter> {
+    registry::CHAPTERS.iter().find(|chapter| chapter.module == example.module())
+}
+
+
+/// Path of the file defining `example`, relative to the repository root.
+pub fn path_of(example: &Example) -> String {
+    format!("src/{}.rs", example.module())
+}
+
+
+/// The example function, along with the items listed in its `shows`.
+pub fn snippet_of(example: &Example) -> Option<Snippet> {
+    let source: &str = chapter_of(example)?.source;
+    let mut snippet: Snippet = extract(source, example.name)?;
+
+    let mut items: Vec<Snippet> = example.shows.iter()
+        .flat_map(|name| extract_items(source, name))
+        .collect();
+    items.sort_by_key(|item| item.line);
+    items.dedup(); // eg. 'impl Area for Circle' is found for both names
+    snippet.items = items;
+    Some(snippet)
+}
+
+
+/// Extracts the function `name` from `source`. Nested functions are ignored:
+/// only a `fn` at the start of a line counts.
+pub fn extract(source: &str, name: &str) -> Option<Snippet> {
+    let lines: Vec<&str> = source.lines().collect();
+    let fn_line: usize = lines.iter()
+        .position(|line| defines(line, &["fn "], name))?;
+    Some(block(&lines, fn_line))
+}
+
+
+/// Extracts every module-level item defining `name`, whether a function,
+/// struct, enum or trait, and every 'impl' block naming it in its header.
+pub fn extract_items(source: &str, name: &str) -> Vec<Snippet> {
+    let lines: Vec<&str> = source.lines().collect();
+    let keywords: &[&str] = &["fn ", "struct ", "enum ", "trait "];
+
+    lines.iter().enumerate()
+        .filter(|(_, line)| defines(line, keywords, name) || implements(line, name))
+        .map(|(i, _)| block(&lines, i))
+        .collect()
+}
+
+
+// Whether `line` starts with one of `keywords`, optionally after 'pub ',
+// followed by `name`: 'pub struct Point<T> {' defines 'Point'.
+fn defines(line: &str, keywords: &[&str], name: &str) -> bool {
+    let line: &str = line.strip_prefix("pub ").unwrap_or(line);
+    keywords.iter().any(|keyword| {
+        line.strip_prefix(keyword)
+            .and_then(|rest| rest.strip_prefix(name))
+            .is_some_and(|rest| !rest.starts_with(|c: char| c.is_alphanumeric() || c == '_'))
+    })
+}
+
+
+// Whether `line` starts an 'impl' block with `name` anywhere in its header:
+// 'impl<T: PartialOrd> PartialOrd for Point<T> {' names 'Point'.
+fn implements(line: &str, name: &str) -> bool {
+    let Some(rest) = line.strip_prefix("impl") else {
+        return false;
+    };
+    let header: &str = rest.split('{').next().unwrap_or(rest);
+    (rest.starts_with('<') || rest.starts_with(' '))
+        && header.split(|c: char| !c.is_alphanumeric() && c != '_').any(|word| word == name)
+}
+
+
+// The item starting at `lines[first]`, with its attributes and the comment
+// block above them, up to its closing brace or ending semicolon.
+fn block(lines: &[&str], first: usize) -> Snippet {
+
+    // attributes belong to the code, the comments above them to the comment.
+    let mut start: usize = first;
+    while start > 0 && lines[start - 1].starts_with("#[") {
+        start -= 1;
+    }
+    let mut comment_start: usize = start;
+    while comment_start > 0 && lines[comment_start - 1].trim_start().starts_with("//") {
+        comment_start -= 1;
+    }
+
+    let mut end: usize = first;
+    let mut depth: usize = 0;
+    let mut opened: bool = false;
+    for (i, line) in lines.iter().enumerate().skip(first) {
+        let chars: Vec<char> = code_chars(line);
+        for &c in &chars {
+            match c {
+                '{' => {
+                    depth += 1;
+                    opened = true;
+                },
+                '}' => depth = depth.saturating_sub(1),
+                _ => {},
+            }
+        }
+        end = i;
+        if opened && depth == 0 {
+            break;
+        }
+        // a unit or tuple struct, such as 'pub struct MyBox<T>(T);'.
+        if !opened && chars.iter().rev().find(|c| !c.is_whitespace()) == Some(&';') {
+            break;
+        }
+    }
+
+    let join = |from: usize, to: usize| -> String {
+        lines[from..to].iter().map(|line| format!("{line}\n")).collect()
+    };
+    Snippet {
+        comment: join(comment_start, start),
+        code: join(start, end + 1),
+        line: comment_start + 1,
+        items: Vec::new(),
+    }
+}
+
+
+// The characters of `line` outside of string literals and `//` comments, so
+// that braces in "{}" format strings are not mistaken for blocks.
+fn code_chars(line: &str) -> Vec<char> {
+    let mut out: Vec<char> = Vec::new();
+    let mut chars = line.chars().peekable();
+    let mut in_string: bool = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            match c {
+                '\\' => { chars.next(); },
+                '"' => in_string = false,
+                _ => {},
+            }
+        } else {
+            match c {
+                '"' => in_string = true,
+                '/' if chars.peek() == Some(&'/') => break,
+                c => out.push(c),
+            }
+        }
+    }
+    out
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "\
+use std::cmp::max;
+
+// unrelated comment
+
+// explains 'sample'
+//
+// over several lines.
+#[allow(dead_code)]
+pub fn sample() {
+    fn helper() -> &'static str { \"}\" } // '}'
+    if true {
+        println!(\"{}\", helper());
+    }
+}
+
+fn sample_two() {}
+";
+
+    #[test]
+    fn extract_function_with_comment() {
+        let snippet: Snippet = extract(SOURCE, "sample").unwrap();
+        assert_eq!(snippet.comment, "// explains 'sample'\n//\n// over several lines.\n");
+        assert!(snippet.code.starts_with("#[allow(dead_code)]\npub fn sample() {\n"));
+        assert!(snippet.code.ends_with("        println!(\"{}\", helper());\n    }\n}\n"));
+        assert_eq!(snippet.line, 5);
+    }
+
+    #[test]
+    fn extract_without_comment() {
+        let snippet: Snippet = extract(SOURCE, "sample_two").unwrap();
+        assert_eq!(snippet.comment, "");
+        assert_eq!(snippet.code, "fn sample_two() {}\n");
+        assert!(extract(SOURCE, "helper").is_none()); // nested
+        assert!(extract(SOURCE, "sam").is_none());
+    }
+
+    const ITEMS: &str = "\
+/// A shape.
+#[derive(Debug)]
+pub struct Square {
+    pub side: u32,
+}
+
+pub struct Wrapper<T>(T);
+
+impl Square {
+    pub fn area(&self) -> u32 { self.side * self.side }
+}
+
+// all shapes have an area.
+pub trait Area {}
+
+impl Area for Square {}
+
+impl<T> Wrapper<T> {}
+
+struct SquareTile;
+";
+
+    #[test]
+    fn extract_items_by_name() {
+        let squares: Vec<Snippet> = extract_items(ITEMS, "Square");
+        let starts: Vec<usize> = squares.iter().map(|item| item.line).collect();
+        assert_eq!(starts, [1, 9, 16]); // not 'SquareTile'
+        assert_eq!(squares[0].comment, "/// A shape.\n");
+        assert!(squares[0].code.starts_with("#[derive(Debug)]\npub struct Square {\n"));
+        assert_eq!(squares[2].code, "impl Area for Square {}\n");
+
+        let areas: Vec<Snippet> = extract_items(ITEMS, "Area");
+        assert_eq!(areas[0].text(), "// all shapes have an area.\npub trait Area {}\n");
+        assert_eq!(areas.len(), 2);
+
+        let wrappers: Vec<Snippet> = extract_items(ITEMS, "Wrapper");
+        assert_eq!(wrappers[0].code, "pub struct Wrapper<T>(T);\n"); // ends at ';'
+        assert_eq!(wrappers[1].code, "impl<T> Wrapper<T> {}\n");
+        assert!(extract_items(ITEMS, "Shape").is_empty());
+    }
+
+    #[test]
+    fn excerpts() {
+        let snippet: Snippet = extract(SOURCE, "sample").unwrap();
+        let block: &str = "if true {\n    println!(\"{}\", helper());\n}\n";
+        assert_eq!(snippet.excerpt("if true", "if true").unwrap(), block); // up to its '}'
+        assert_eq!(
+            snippet.excerpt("fn helper", "println!").unwrap(),
+            format!("fn helper() -> &'static str {{ \"}}\" }} // '}}'\n{block}"),
+        );
+        assert_eq!(snippet.excerpt("explains", "explains"), None); // comments are not code
+    }
+
+    #[test]
+    fn empty_bodies() {
+        assert!(!extract(SOURCE, "sample").unwrap().body_is_empty());
+        assert!(extract(SOURCE, "sample_two").unwrap().body_is_empty());
+
+        let commented: &str = "fn todo() {\n    \n    // later\n}\n";
+        assert!(extract(commented, "todo").unwrap().body_is_empty());
+    }
+
+    #[test]
+    fn every_example_has_source() {
+        for example in registry::all() {
+            let snippet: Snippet = snippet_of(example).unwrap();
+            assert!(snippet.code.contains(&format!("fn {}(", example.name)));
+            assert!(snippet.code.trim_end().ends_with('}'), "{}", example.id());
+            for name in example.shows {
+                let source: &str = chapter_of(example).unwrap().source;
+                assert!(!extract_items(source, name).is_empty(), "{}: {name}", example.id());
+            }
+        }
+
+        let example: &Example = registry::find("ch4_ownership::switch_between_references").unwrap();
+        let snippet: Snippet = snippet_of(example).unwrap();
+        assert!(snippet.comment.starts_with("/// Mutable references has a restriction"));
+        assert!(snippet.code.contains("let mutab_1: &mut String = &mut bob_name;"));
+        assert!(snippet.items.is_empty());
+    }
+
+    #[test]
+    fn snippets_show_their_items() {
+        let example: Example = Example::new(
+            5, "syntax_examples::ch5_struct", "struct_method", crate::ch5_struct::struct_method,
+        ).shows(&["Rectangle"]);
+        let snippet: Snippet = snippet_of(&example).unwrap();
+        assert_eq!(snippet.items.len(), 2);
+        assert!(snippet.items[0].code.starts_with("pub struct Rectangle"));
+        assert!(snippet.items[1].code.starts_with("impl Rectangle {"));
+
+        let text: String = snippet.text();
+        assert!(text.find("impl Rectangle {").unwrap() < text.find("pub fn struct_method()").unwrap());
+    }
+}
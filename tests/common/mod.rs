@@ -0,0 +1,22 @@
+//! Helpers shared by the integration tests. Living in 'common/mod.rs' rather
+//! than 'common.rs', this file is not compiled as a test crate of its own;
+//! each test file includes it with 'mod common;'.
+
+use syntax_examples::registry::{self, Example};
+use syntax_examples::runner::{self, Outcome};
+
+
+/// The example with the given id; fails the test when there is none.
+pub fn example(id: &str) -> &'static Example {
+    registry::find(id).unwrap_or_else(|| panic!("no example named `{id}`"))
+}
+
+
+/// Runs an example the way 'cargo run -- run' does, and fails the test with
+/// the example's panic message when it fails.
+pub fn assert_passes(id: &str) {
+    match runner::run_one(example(id)) {
+        Outcome::Passed => {}
+        outcome => panic!("`{id}` did not pass: {outcome:?}"),
+    }
+}
@@ -0,0 +1,43 @@
+//! Integration tests: every example of the catalog passes when run the way
+//! 'cargo run -- run' runs it.
+
+mod common;
+
+use syntax_examples::registry::{self, Example};
+use syntax_examples::runner::{self, Outcome};
+use syntax_examples::sandbox::Setup;
+
+
+#[test]
+fn every_example_passes() {
+    for example in registry::all() {
+        common::assert_passes(&example.id());
+    }
+}
+
+
+#[test]
+fn examples_are_found_by_id() {
+    for example in registry::all() {
+        let found = common::example(&example.id());
+        assert_eq!(found.name, example.name);
+    }
+}
+
+
+#[test]
+fn runs_start_clean() {
+    // 'refcell_borrows' catches a panic of its own, which is not reported...
+    common::assert_passes("ch15_smart_pointers::refcell_borrows");
+
+    // ...neither here, where a sandboxed example fails with its own message...
+    let failing: Example = Example::new(9, "tests", "fails_in_sandbox", || panic!("on purpose"))
+        .setup(Setup::FileAbsent { path: "hello.txt" });
+    match runner::run_one(&failing) {
+        Outcome::Failed(failure) => assert_eq!(failure.message, "on purpose"),
+        outcome => panic!("expected a failure, got {outcome:?}"),
+    }
+
+    // ...and leaves its sandbox, or entering the next one would wait forever.
+    common::assert_passes("ch9_errors::match_on_error");
+}
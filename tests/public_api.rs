@@ -0,0 +1,97 @@
+//! Integration tests: the chapters as another crate sees them, through the
+//! public API of 'syntax_examples' only.
+
+mod common;
+
+use std::collections::HashMap;
+
+use syntax_examples::ch3_control_flow::plus_one;
+use syntax_examples::ch4_ownership::first_word;
+use syntax_examples::ch5_struct::Rectangle;
+use syntax_examples::ch6_enum::{opt_max, opt_max_if_let};
+use syntax_examples::ch7_modules::{back_of_house::Breakfast, hosting};
+use syntax_examples::ch8_collections::get_price;
+use syntax_examples::ch9_errors::last_chr_of_fst_line;
+use syntax_examples::ch10_generics::{longest, Area, Circle, ImportantExcerpt, Square};
+use syntax_examples::ch11_testing::{add_two, greeting, Guess};
+
+
+#[test]
+fn functions() {
+    assert_eq!(plus_one(232), 233);
+    assert_eq!(first_word(&String::from("bob likes alice")), "bob");
+    assert_eq!(last_chr_of_fst_line("bob likes alice\nalice likes rust"), Some('e'));
+}
+
+
+#[test]
+fn options() {
+    for (x, y) in [(Some(1), Some(2)), (None, Some(2)), (Some(1), None), (None, None)] {
+        assert_eq!(opt_max(x, y), opt_max_if_let(x, y), "opt_max({x:?}, {y:?})");
+    }
+}
+
+
+#[test]
+fn structs_and_traits() {
+    let r = Rectangle{width: 5, height: 3};
+    assert_eq!(r.area(), 15);
+
+    let shapes: Vec<Box<dyn Area>> = vec![
+        Box::new(Circle{center_x: 0.0, center_y: 0.0, radius: 1.0}),
+        Box::new(Square{center_x: 0.0, center_y: 0.0, side_len: 2.0}),
+    ];
+    let total: f32 = shapes.iter().map(|shape| shape.area()).sum();
+    assert!(total > 7.0 && total < 7.2, "total area was {total}");
+}
+
+
+#[test]
+fn collections() {
+    let price: HashMap<String, i32> = HashMap::from([("bread".to_string(), 4)]);
+    assert_eq!(get_price(&"bread".to_string(), &price), 4);
+    assert_eq!(get_price(&"acorn".to_string(), &price), 0);
+}
+
+
+#[test]
+fn lifetimes() {
+    let novel: String = String::from("Call me Ishmael. Some years ago...");
+    let excerpt = ImportantExcerpt{part: novel.split('.').next().unwrap()};
+    assert_eq!(longest(excerpt.part(), "Ishmael"), "Call me Ishmael");
+}
+
+
+// only what is 'pub' all the way down is reachable from here.
+#[test]
+fn modules() {
+    assert_eq!(hosting::add_to_waitlist("bob"), "bob is waiting for one of 4 tables");
+    assert_eq!(Breakfast::summer("rye").fruit(), "peaches");
+}
+
+
+#[test]
+fn testing() -> Result<(), String> {
+    assert_eq!(add_two(2), 4);
+    assert!(greeting("Carol").contains("Carol"));
+    let guess: Guess = Guess::new(50);
+    if guess.value() == 50 {
+        Ok(())
+    } else {
+        Err(format!("guessed {} instead of 50", guess.value()))
+    }
+}
+
+
+#[test]
+#[should_panic(expected = "greater than or equal to 1")]
+fn guess_out_of_range() {
+    Guess::new(-1);
+}
+
+
+#[test]
+fn chapter_examples() {
+    common::assert_passes("ch11_testing::expected_panics");
+    assert_eq!(common::example("ch7_modules::visibility").chapter, 7);
+}